@@ -1,6 +0,0 @@
-use super::Literal;
-
-pub enum BranchingDecision {
-    Assumption { assumption_literal: Literal },
-    StandardDecision { decision_literal: Literal },
-}
@@ -1,5 +0,0 @@
-#[derive(PartialEq, Eq, Debug)]
-pub enum ClauseAdditionOutcome {
-    Infeasible,
-    NoConflictDetected,
-}
@@ -1,4 +0,0 @@
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-pub struct ClauseReference {
-    pub id: u32,
-}
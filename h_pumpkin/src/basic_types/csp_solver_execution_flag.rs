@@ -1,6 +0,0 @@
-pub enum CSPSolverExecutionFlag {
-    Feasible,
-    Infeasible,
-    InfeasibleUnderAssumptions,
-    Timeout,
-}
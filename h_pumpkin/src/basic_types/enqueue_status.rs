@@ -1,4 +0,0 @@
-pub enum EnqueueStatus {
-    ShouldEnqueue,
-    DoNotEnqueue,
-}
@@ -1,6 +0,0 @@
-#[derive(Copy, Clone)]
-pub enum FileFormat {
-    CnfDimacsPLine,
-    WcnfDimacsPLine,
-    MaxSAT2022,
-}
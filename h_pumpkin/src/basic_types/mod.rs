@@ -1,53 +0,0 @@
-mod branching_decision;
-mod clause;
-mod clause_addition_outcome;
-mod clause_reference;
-mod csp_solver_execution_flag;
-mod enqueue_status;
-mod file_format;
-mod function;
-mod instance;
-mod integer_variable;
-mod key_value_heap;
-mod literal;
-mod predicate;
-mod propagation_status_clausal;
-mod propagation_status_cp;
-mod propagation_status_cp_one_step;
-mod propagator_identifier;
-mod propositional_conjunction;
-mod propositional_variable;
-mod pumpkin_execution_flag;
-mod solution;
-mod solution_tracker;
-mod solution_value_pair;
-mod stopwatch;
-mod weighted_literal;
-
-pub use branching_decision::BranchingDecision;
-pub use clause::Clause;
-pub use clause_addition_outcome::ClauseAdditionOutcome;
-pub use clause_reference::ClauseReference;
-pub use csp_solver_execution_flag::CSPSolverExecutionFlag;
-pub use enqueue_status::EnqueueStatus;
-pub use file_format::FileFormat;
-pub use function::Function;
-pub use instance::Instance;
-pub use integer_variable::IntegerVariable;
-pub use integer_variable::IntegerVariableGeneratorIterator;
-pub use key_value_heap::KeyValueHeap;
-pub use literal::Literal;
-pub use predicate::Predicate;
-pub use propagation_status_clausal::PropagationStatusClausal;
-pub use propagation_status_cp::PropagationStatusCP;
-pub use propagation_status_cp_one_step::PropagationStatusOneStepCP;
-pub use propagator_identifier::PropagatorIdentifier;
-pub use propositional_conjunction::PropositionalConjunction;
-pub use propositional_variable::PropositionalVariable;
-pub use propositional_variable::PropositionalVariableGeneratorIterator;
-pub use pumpkin_execution_flag::PumpkinExecutionFlag;
-pub use solution::Solution;
-pub use solution_tracker::SolutionTracker;
-pub use solution_value_pair::SolutionValuePair;
-pub use stopwatch::Stopwatch;
-pub use weighted_literal::WeightedLiteral;
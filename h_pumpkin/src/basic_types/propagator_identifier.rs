@@ -1,4 +0,0 @@
-#[derive(PartialEq, Eq, Copy, Clone, Hash)]
-pub struct PropagatorIdentifier {
-    pub id: u32,
-}
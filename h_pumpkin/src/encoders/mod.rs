@@ -1,7 +0,0 @@
-mod cardinality_networks_encoder;
-mod generalised_totaliser_encoder;
-mod upper_bound_encoder;
-
-pub use cardinality_networks_encoder::CardinalityNetworkEncoder;
-pub use generalised_totaliser_encoder::GeneralisedTotaliserEncoder;
-pub use upper_bound_encoder::{EncodingStatus, UpperBoundEncoder};
@@ -1,13 +0,0 @@
-mod assignments_integer;
-mod cp_engine_data_structures;
-mod domain_manager;
-mod propagator_queue;
-mod watch_list_cp;
-
-pub use assignments_integer::AssignmentsInteger;
-pub use assignments_integer::ConstraintProgrammingTrailEntry;
-pub use assignments_integer::DomainOperationOutcome;
-pub use cp_engine_data_structures::CPEngineDataStructures;
-pub use domain_manager::DomainManager;
-pub use propagator_queue::PropagatorQueue;
-pub use watch_list_cp::WatchListCP;
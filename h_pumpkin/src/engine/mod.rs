@@ -1,15 +0,0 @@
-mod constraint_satisfaction_solver;
-mod cp;
-mod debug_helper;
-mod linear_search;
-mod pumpkin;
-mod sat;
-mod sat_cp_mediator;
-
-pub use constraint_satisfaction_solver::{ConstraintSatisfactionSolver, SatisfactionSolverOptions};
-pub use cp::*;
-pub use debug_helper::DebugHelper;
-pub use linear_search::{LinearSearch, UpperBoundEncoding};
-pub use pumpkin::Pumpkin;
-pub use sat::*;
-pub use sat_cp_mediator::SATCPMediator;
@@ -1,13 +0,0 @@
-mod assignments_propositional;
-mod clause_allocator;
-mod propositional_value_selector;
-mod propositional_variable_selector;
-mod sat_engine_data_structures;
-
-pub use assignments_propositional::AssignmentsPropositional;
-pub use clause_allocator::ClauseAllocator;
-pub use propositional_value_selector::PropositionalValueSelector;
-pub use propositional_variable_selector::PropositionalVariableSelector;
-pub use sat_engine_data_structures::{
-    LearnedClauseSortingStrategy, SATDataStructuresInternalParameters, SATEngineDataStructures,
-};
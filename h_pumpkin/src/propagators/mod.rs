@@ -1,7 +0,0 @@
-mod clausal_propagator;
-mod constraint_programming_propagator;
-mod simple_linear_inequality_propagator;
-
-pub use clausal_propagator::ClausalPropagator;
-pub use constraint_programming_propagator::ConstraintProgrammingPropagator;
-pub use simple_linear_inequality_propagator::SimpleLinearInequalityPropagator;
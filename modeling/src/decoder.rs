@@ -0,0 +1,413 @@
+use crate::variable_map::{SegmentVariables, VariableMap};
+use serde::Serialize;
+use std::{collections::HashSet, fmt::Display, fs, path::Path, str::FromStr};
+use thiserror::Error;
+
+/// Why a solution could not be turned into a schedule
+#[derive(Error, Debug)]
+pub enum DecodeError {
+    #[error("Failed to read file {file}, more details: {source}")]
+    Io {
+        file: String,
+        source: std::io::Error,
+    },
+    #[error("{file} is not a valid variable map, more details: {source}")]
+    InvalidMap {
+        file: String,
+        source: serde_json::Error,
+    },
+    #[error(
+        "{file} has {variables} variables, but its map has {expected}. Was it generated again?"
+    )]
+    MismatchedMap {
+        file: String,
+        variables: usize,
+        expected: usize,
+    },
+    #[error("{file} does not contain a solution, the solver reported '{status}'")]
+    NoSolution { file: String, status: String },
+    #[error("{file}:{line}: '{token}' is not a valid literal")]
+    InvalidLiteral {
+        file: String,
+        line: usize,
+        token: String,
+    },
+    #[error("No mode of project {project} is selected")]
+    NoModeSelected { project: usize },
+}
+
+/// The output of a MaxSAT solver: the status, the objective and the 'v' lines of the solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    pub status: Option<String>,
+    pub objective: Option<u64>,
+    true_variables: HashSet<usize>,
+}
+
+impl Solution {
+    /// Reads both the list of literals printed by pumpkin and the string of zeros and ones of
+    /// the MaxSAT evaluation format
+    pub fn parse(file: &str, contents: &str) -> Result<Self, DecodeError> {
+        let mut status = None;
+        let mut objective = None;
+        let mut true_variables = None;
+        for (index, line) in contents.lines().enumerate() {
+            let invalid = |token: &str| DecodeError::InvalidLiteral {
+                file: file.to_owned(),
+                line: index + 1,
+                token: token.to_owned(),
+            };
+            let mut tokens = line.split_whitespace();
+            match tokens.next() {
+                Some("s") => status = Some(tokens.collect::<Vec<&str>>().join(" ")),
+                // Only the last objective is the one of the solution
+                Some("o") => objective = tokens.next().and_then(|o| o.parse().ok()),
+                Some("v") => {
+                    let variables: &mut HashSet<usize> = true_variables.get_or_insert_default();
+                    let tokens: Vec<&str> = tokens.collect();
+                    match tokens.as_slice() {
+                        [bits] if bits.len() > 1 && bits.chars().all(|c| c == '0' || c == '1') => {
+                            variables.extend(
+                                bits.char_indices()
+                                    .filter(|(_, c)| *c == '1')
+                                    .map(|(index, _)| index + 1),
+                            );
+                        }
+                        literals => {
+                            for literal in literals {
+                                let value: i64 = literal.parse().map_err(|_| invalid(literal))?;
+                                if value > 0 {
+                                    variables.insert(value as usize);
+                                }
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        match true_variables {
+            Some(true_variables) => Ok(Self {
+                status,
+                objective,
+                true_variables,
+            }),
+            None => Err(DecodeError::NoSolution {
+                file: file.to_owned(),
+                status: status.unwrap_or_else(|| "nothing".to_owned()),
+            }),
+        }
+    }
+
+    pub fn is_true(&self, variable: usize) -> bool {
+        self.true_variables.contains(&variable)
+    }
+}
+
+/// A solution turned back into a schedule. Times start at 0, and a segment that starts at `start`
+/// is done at `end`, set-up included.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DecodedSchedule {
+    pub makespan: usize,
+    pub objective: Option<u64>,
+    pub set_up_cost: usize,
+    pub projects: Vec<DecodedProject>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DecodedProject {
+    pub id: usize,
+    pub mode: usize,
+    /// Empty for a project that was not started, which can only happen to a dummy project
+    pub start: Option<usize>,
+    pub end: Option<usize>,
+    /// The segments that were started, in the order they run
+    pub segments: Vec<DecodedSegment>,
+    pub preemptions: Vec<Preemption>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DecodedSegment {
+    pub id: usize,
+    pub start_jiff: usize,
+    pub end_jiff: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// The project is interrupted after `after_jiffy` at time `at`, and picked up again at `resumed_at`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Preemption {
+    pub after_jiffy: usize,
+    pub at: usize,
+    pub resumed_at: usize,
+}
+
+impl DecodedSchedule {
+    pub fn decode(map: &VariableMap, solution: &Solution) -> Result<Self, DecodeError> {
+        let mut projects = Vec::with_capacity(map.projects.len());
+        for project in map.projects.iter() {
+            let mode = if project.mode_variables.is_empty() {
+                0
+            } else {
+                project
+                    .mode_variables
+                    .iter()
+                    .position(|variable| solution.is_true(*variable))
+                    .ok_or(DecodeError::NoModeSelected {
+                        project: project.id,
+                    })?
+            };
+            let mut segments: Vec<DecodedSegment> = project
+                .segments
+                .iter()
+                .filter(|segment| segment.mode == mode)
+                .filter_map(|segment| decode_segment(segment, solution))
+                .collect();
+            segments.sort_by_key(|segment| (segment.start, segment.start_jiff));
+            let preemptions = segments
+                .windows(2)
+                .map(|pair| Preemption {
+                    after_jiffy: pair[0].end_jiff,
+                    at: pair[0].end,
+                    resumed_at: pair[1].start,
+                })
+                .collect();
+            projects.push(DecodedProject {
+                id: project.id,
+                mode,
+                start: segments.iter().map(|segment| segment.start).min(),
+                end: segments.iter().map(|segment| segment.end).max(),
+                segments,
+                preemptions,
+            });
+        }
+        Ok(Self {
+            makespan: projects
+                .iter()
+                .filter_map(|project| project.end)
+                .max()
+                .unwrap_or(0),
+            objective: solution.objective,
+            set_up_cost: map.set_up_cost,
+            projects,
+        })
+    }
+
+    pub fn write_to_string(&self, format: ScheduleFormat) -> String {
+        match format {
+            ScheduleFormat::Json => {
+                serde_json::to_string_pretty(self).expect("A schedule can always be serialised")
+                    + "\n"
+            }
+            ScheduleFormat::Csv => self.write_to_csv(),
+        }
+    }
+
+    /// One row per started segment
+    fn write_to_csv(&self) -> String {
+        let mut csv = "project,mode,segment,start_jiff,end_jiff,start,end\n".to_string();
+        for project in self.projects.iter() {
+            for segment in project.segments.iter() {
+                csv += &format!(
+                    "{},{},{},{},{},{},{}\n",
+                    project.id,
+                    project.mode,
+                    segment.id,
+                    segment.start_jiff,
+                    segment.end_jiff,
+                    segment.start,
+                    segment.end
+                );
+            }
+        }
+        csv
+    }
+}
+
+/// Nothing forces a segment to start only once, the earliest start is the one that matters
+fn decode_segment(segment: &SegmentVariables, solution: &Solution) -> Option<DecodedSegment> {
+    let start = segment
+        .start_variables
+        .iter()
+        .filter(|s_var| solution.is_true(s_var.variable))
+        .map(|s_var| s_var.time)
+        .min()?;
+    Some(DecodedSegment {
+        id: segment.id,
+        start_jiff: segment.start_jiff,
+        end_jiff: segment.end_jiff,
+        start,
+        end: start + segment.duration,
+    })
+}
+
+/// Decodes the solution to a generated file, using the variable map next to it
+pub fn decode_file(wcnf: &Path, solution: &Path) -> Result<DecodedSchedule, DecodeError> {
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|source| DecodeError::Io {
+            file: path.display().to_string(),
+            source,
+        })
+    };
+    let map_path = VariableMap::path_for(wcnf);
+    let map: VariableMap =
+        serde_json::from_str(&read(&map_path)?).map_err(|source| DecodeError::InvalidMap {
+            file: map_path.display().to_string(),
+            source,
+        })?;
+    // The header is 'p wcnf [variables] [clauses] [top]'
+    let variables = read(wcnf)?
+        .lines()
+        .find(|line| line.starts_with("p "))
+        .and_then(|line| line.split_whitespace().nth(2))
+        .and_then(|variables| variables.parse().ok());
+    if variables != Some(map.variables) {
+        return Err(DecodeError::MismatchedMap {
+            file: wcnf.display().to_string(),
+            variables: variables.unwrap_or(0),
+            expected: map.variables,
+        });
+    }
+    let file = solution.display().to_string();
+    let solution = Solution::parse(&file, &read(solution)?)?;
+    DecodedSchedule::decode(&map, &solution)
+}
+
+/// The formats a decoded schedule can be written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleFormat {
+    Json,
+    Csv,
+}
+
+impl FromStr for ScheduleFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(ScheduleFormat::Json),
+            "csv" => Ok(ScheduleFormat::Csv),
+            value => Err(format!("'{value}' is not a valid schedule format")),
+        }
+    }
+}
+
+impl Display for ScheduleFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduleFormat::Json => write!(f, "json"),
+            ScheduleFormat::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DecodeError, DecodedSchedule, Preemption, Solution};
+    use crate::variable_map::{ProjectVariables, SegmentVariables, TimedVariable, VariableMap};
+
+    fn timed(variables: &[(usize, usize)]) -> Vec<TimedVariable> {
+        variables
+            .iter()
+            .map(|(variable, time)| TimedVariable {
+                variable: *variable,
+                time: *time,
+            })
+            .collect()
+    }
+
+    // A project of 3 jiffies in two modes, the first of which is split after jiffy 1
+    fn map() -> VariableMap {
+        let segment = |id, mode, start_jiff, end_jiff, duration, starts: &[(usize, usize)]| {
+            SegmentVariables {
+                id,
+                mode,
+                start_jiff,
+                end_jiff,
+                duration,
+                start_variables: timed(starts),
+                active_variables: Vec::new(),
+            }
+        };
+        VariableMap {
+            variables: 12,
+            set_up_cost: 1,
+            projects: vec![ProjectVariables {
+                id: 2,
+                mode_variables: vec![11, 12],
+                segments: vec![
+                    segment(0, 0, 1, 1, 1, &[(1, 0), (2, 1), (3, 2)]),
+                    segment(1, 0, 2, 3, 3, &[(4, 0), (5, 1), (6, 2), (7, 3)]),
+                    segment(2, 1, 1, 2, 2, &[(8, 0), (9, 1), (10, 2)]),
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn parse_literals_and_bits() {
+        let literals = Solution::parse("out", "c comment\ns OPTIMAL\no 4\nv 0 -1 2 -3\n").unwrap();
+        assert_eq!(literals.status.as_deref(), Some("OPTIMAL"));
+        assert_eq!(literals.objective, Some(4));
+        assert!(literals.is_true(2));
+        assert!(!literals.is_true(1) && !literals.is_true(3));
+
+        let bits = Solution::parse("out", "s OPTIMUM FOUND\nv 0101\n").unwrap();
+        assert!(bits.is_true(2) && bits.is_true(4));
+        assert!(!bits.is_true(1) && !bits.is_true(3));
+    }
+
+    #[test]
+    fn parse_without_solution() {
+        assert!(matches!(
+            Solution::parse("out", "s UNSATISFIABLE\n"),
+            Err(DecodeError::NoSolution { status, .. }) if status == "UNSATISFIABLE"
+        ));
+        assert!(matches!(
+            Solution::parse("out", "s OPTIMAL\nv 1 x\n"),
+            Err(DecodeError::InvalidLiteral { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn decode_preempted_project() {
+        let solution = Solution::parse("out", "v 1 -2 -3 -4 -5 -6 7 -8 -9 -10 11 -12").unwrap();
+        let schedule = DecodedSchedule::decode(&map(), &solution).unwrap();
+        let project = &schedule.projects[0];
+        assert_eq!(project.mode, 0);
+        assert_eq!((project.start, project.end), (Some(0), Some(6)));
+        assert_eq!(project.segments.len(), 2);
+        assert_eq!(
+            project.preemptions,
+            vec![Preemption {
+                after_jiffy: 1,
+                at: 1,
+                resumed_at: 3,
+            }]
+        );
+        assert_eq!(schedule.makespan, 6);
+        assert_eq!(
+            schedule.write_to_string(super::ScheduleFormat::Csv),
+            "project,mode,segment,start_jiff,end_jiff,start,end\n2,0,0,1,1,0,1\n2,0,1,2,3,3,6\n"
+        );
+    }
+
+    #[test]
+    fn decode_selected_mode_only() {
+        // The segments of the first mode are started as well, but the second mode is selected
+        let solution = Solution::parse("out", "v 1 5 9 -11 12").unwrap();
+        let schedule = DecodedSchedule::decode(&map(), &solution).unwrap();
+        let project = &schedule.projects[0];
+        assert_eq!(project.mode, 1);
+        assert_eq!(project.segments.len(), 1);
+        assert_eq!((project.start, project.end), (Some(1), Some(3)));
+        assert!(project.preemptions.is_empty());
+
+        let solution = Solution::parse("out", "v 1 5 -11 -12").unwrap();
+        assert!(matches!(
+            DecodedSchedule::decode(&map(), &solution),
+            Err(DecodeError::NoModeSelected { project: 2 })
+        ));
+    }
+}
@@ -0,0 +1,320 @@
+use crate::schedule::Schedule;
+use shared::segment::Segment;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+/// How the schedule behind an initial assignment was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssignmentSource {
+    /// By [`serial_schedule`], so the assignment satisfies the hard clauses
+    SerialSchedule,
+    /// By starting every project at its earliest start, ignoring the resources. Only useful as
+    /// phase hints
+    EarliestStarts,
+}
+
+/// Values for the variables of a generated file to start the search from. Only the start, active
+/// and mode variables are assigned, the variables of a clausal resource encoding are left out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitialAssignment {
+    pub source: AssignmentSource,
+    pub makespan: usize,
+    /// Sorted by variable
+    pub literals: Vec<i64>,
+}
+
+impl InitialAssignment {
+    /// Uses the serial schedule if there is one, and the earliest starts otherwise. Should be
+    /// called once all variables of the schedule have been generated
+    pub fn new(schedule: &Schedule) -> Self {
+        let modes = cheapest_modes(schedule);
+        let (source, starts) = match serial_schedule(schedule, &modes) {
+            Some(starts) => (AssignmentSource::SerialSchedule, starts),
+            None => (
+                AssignmentSource::EarliestStarts,
+                earliest_starts(schedule, &modes),
+            ),
+        };
+        Self {
+            source,
+            makespan: starts
+                .iter()
+                .map(|(segment, start)| start + schedule.graph.segment(*segment).duration())
+                .max()
+                .unwrap_or(0),
+            literals: literals(schedule, &modes, &starts),
+        }
+    }
+
+    /// Whether the assignment is a solution of a generated file with `num_variables` variables.
+    /// It must come from a serial schedule and assign every variable, so there may not be any
+    /// variables of a clausal resource encoding
+    pub fn is_solution(&self, num_variables: usize) -> bool {
+        self.source == AssignmentSource::SerialSchedule && self.literals.len() == num_variables
+    }
+
+    /// The initial assignment of '2Fj301_1.wcnf' is '2Fj301_1.init' if it is a solution, which is
+    /// a warm start for pumpkin's `--initial-solution`. Otherwise it is '2Fj301_1.phases', which
+    /// is only useful for `--phase-hints`
+    pub fn path_for(wcnf: &Path, is_solution: bool) -> PathBuf {
+        if is_solution {
+            wcnf.with_extension("init")
+        } else {
+            wcnf.with_extension("phases")
+        }
+    }
+
+    /// A comment with the source, followed by the literals as a 'v' line like in solver output
+    pub fn write_to_string(&self) -> String {
+        let source = match self.source {
+            AssignmentSource::SerialSchedule => "serial schedule",
+            AssignmentSource::EarliestStarts => "earliest starts, ignoring the resources",
+        };
+        format!("c {} with makespan {}\n", source, self.makespan)
+            + "v"
+            + &self
+                .literals
+                .iter()
+                .map(|literal| format!(" {}", literal))
+                .collect::<String>()
+            + "\n"
+    }
+}
+
+/// Per project, the mode that uses the least of the nonrenewable resources, and the shortest of
+/// those
+fn cheapest_modes(schedule: &Schedule) -> Vec<usize> {
+    let renewable = schedule.resources.len();
+    schedule
+        .projects
+        .iter()
+        .map(|project| {
+            let modes = project.modes().iter().enumerate();
+            modes
+                .min_by_key(|(_, mode)| {
+                    (
+                        mode.resource[renewable..].iter().sum::<usize>(),
+                        mode.duration,
+                    )
+                })
+                .map(|(index, _)| index)
+                .unwrap()
+        })
+        .collect()
+}
+
+/// The segment that runs a project from start to finish in the mode, without preemption
+fn whole_segment(schedule: &Schedule, project: usize, mode: usize) -> &Segment {
+    let duration = schedule.projects[project].modes()[mode].duration;
+    schedule.projects[project]
+        .segments()
+        .iter()
+        .map(|id| schedule.graph.segment(*id))
+        .find(|segment| {
+            segment.mode == mode && segment.start_jiff <= 1 && segment.end_jiff() == duration
+        })
+        .expect("Every mode has a segment covering all of its jiffies")
+}
+
+/// Serial schedule generation: of the projects whose predecessors have all been scheduled, the
+/// one with the earliest start is scheduled next, in the given mode and without preemption, at
+/// the first time that its renewable resources are available. Returns the start of the whole
+/// segment of every project, or `None` if it does not fit in the horizon or the nonrenewable
+/// budgets.
+pub fn serial_schedule(schedule: &Schedule, modes: &[usize]) -> Option<Vec<(usize, usize)>> {
+    let renewable = schedule.resources.len();
+    for (index, budget) in schedule.nonrenewable.iter().enumerate() {
+        let usage: usize = schedule
+            .projects
+            .iter()
+            .zip(modes)
+            .map(|(project, mode)| project.modes()[*mode].resource[renewable + index])
+            .sum();
+        if usage > *budget {
+            return None;
+        }
+    }
+
+    let index_of: HashMap<usize, usize> = schedule
+        .projects
+        .iter()
+        .enumerate()
+        .map(|(index, project)| (project.id(), index))
+        .collect();
+    let mut finish: Vec<Option<usize>> = vec![None; schedule.projects.len()];
+    let mut starts: Vec<(usize, usize)> = vec![(0, 0); schedule.projects.len()];
+    // The renewable resources in use at every time
+    let mut usage: Vec<Vec<usize>> = Vec::new();
+    for _ in 0..schedule.projects.len() {
+        let predecessors_done = |project: usize| {
+            schedule.projects[project]
+                .precedence()
+                .iter()
+                .map(|id| finish[index_of[id]])
+                .collect::<Option<Vec<usize>>>()
+        };
+        let (project, ready) = (0..schedule.projects.len())
+            .filter(|project| finish[*project].is_none())
+            .filter_map(|project| Some((project, predecessors_done(project)?)))
+            .min_by_key(|(project, _)| {
+                (
+                    whole_segment(schedule, *project, modes[*project]).early_start,
+                    *project,
+                )
+            })?;
+        let segment = whole_segment(schedule, project, modes[project]);
+        let demand = &segment.resource[..renewable];
+        // Like the encoding, a segment that starts at t uses its resources from t up to and
+        // including t + duration
+        let fits = |start: usize| {
+            (start..start + segment.duration() + 1).all(|time| {
+                demand.iter().enumerate().all(|(resource, amount)| {
+                    usage.get(time).map_or(0, |used| used[resource]) + amount
+                        <= schedule.resources[resource]
+                })
+            })
+        };
+        let ready = ready
+            .into_iter()
+            .max()
+            .unwrap_or(0)
+            .max(segment.early_start);
+        let start = (ready..segment.latest_start + 1).find(|start| fits(*start))?;
+        for time in start..start + segment.duration() + 1 {
+            if usage.len() <= time {
+                usage.resize(time + 1, vec![0; renewable]);
+            }
+            for (used, amount) in usage[time].iter_mut().zip(demand) {
+                *used += amount;
+            }
+        }
+        finish[project] = Some(start + segment.duration());
+        starts[project] = (segment.id(), start);
+    }
+    Some(starts)
+}
+
+/// Every project in the given mode and without preemption at its earliest start
+fn earliest_starts(schedule: &Schedule, modes: &[usize]) -> Vec<(usize, usize)> {
+    (0..schedule.projects.len())
+        .map(|project| {
+            let segment = whole_segment(schedule, project, modes[project]);
+            (segment.id(), segment.early_start)
+        })
+        .collect()
+}
+
+fn literals(schedule: &Schedule, modes: &[usize], starts: &[(usize, usize)]) -> Vec<i64> {
+    let graph = &schedule.graph;
+    let renewable = schedule.resources.len();
+    let mut started: HashMap<usize, Vec<usize>> = starts
+        .iter()
+        .map(|(segment, start)| (*segment, vec![*start]))
+        .collect();
+    // The objective charges every start variable of the last project that is false, so it is
+    // started again at every later time. This is harmless if it does not take any resources
+    if let Some((segment, start)) = starts.last() {
+        let segment = graph.segment(*segment);
+        if segment.duration() == 0 && segment.resource[..renewable].iter().all(|r| *r == 0) {
+            started.insert(segment.id(), (*start..segment.latest_start + 1).collect());
+        }
+    }
+
+    let mut literals: Vec<i64> = Vec::new();
+    let sign = |value: bool, id: usize| if value { id as i64 } else { -(id as i64) };
+    for (project, mode) in schedule.projects.iter().zip(modes) {
+        for (index, variable) in project.mode_variables().iter().enumerate() {
+            literals.push(sign(index == *mode, *variable));
+        }
+        for segment in project.segments().iter().map(|id| graph.segment(*id)) {
+            let starts = started.get(&segment.id()).map_or(&[][..], |s| s.as_slice());
+            for s_var in segment.variables.iter() {
+                literals.push(sign(starts.contains(&s_var.time()), s_var.id()));
+            }
+            for u_var in segment.uvariables.iter() {
+                let active = starts.iter().any(|start| {
+                    *start <= u_var.time_at() && u_var.time_at() <= start + segment.duration()
+                });
+                literals.push(sign(active, u_var.id()));
+            }
+        }
+    }
+    literals.sort_by_key(|literal| literal.abs());
+    literals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssignmentSource, InitialAssignment};
+    use crate::{
+        decoder::{DecodedSchedule, Solution},
+        readerSM::create_projects,
+        schedule::Schedule,
+        validator::validate,
+        variable_map::VariableMap,
+    };
+    use shared::{id_generator::IdGenerator, project::Mode};
+
+    // 1 -> 2 -> 4 and 1 -> 3 -> 4, where 2 and 3 both need 2 of the 3 available. Returns the
+    // number of variables
+    fn instance(capacity: usize) -> (Schedule, usize) {
+        let modes = vec![
+            vec![Mode::new(0, vec![0])],
+            vec![Mode::new(2, vec![2])],
+            vec![Mode::new(1, vec![2])],
+            vec![Mode::new(0, vec![0])],
+        ];
+        let successors = vec![vec![2, 3], vec![4], vec![4], vec![]];
+        let (projects, mut graph) = create_projects(modes, successors, vec![1, 2, 3, 4]);
+        for segment in graph.segments_mut() {
+            segment.add_set_up_time(1);
+        }
+        let early_starts = graph.earliest_starts();
+        // More room than the critical path, so that 2 and 3 can run one after the other
+        let horizon = graph.critical_path() + 3;
+        let mut id_gen = IdGenerator::generator_for_sat();
+        for (segment, early_start) in graph.segments_mut().iter_mut().zip(early_starts) {
+            segment.generate_SAT_vars(&mut id_gen, early_start, horizon);
+        }
+        let schedule = Schedule::new(projects, graph, vec![capacity], Vec::new());
+        (schedule, id_gen.current_asignment())
+    }
+
+    fn decode(
+        schedule: &Schedule,
+        variables: usize,
+        assignment: &InitialAssignment,
+    ) -> DecodedSchedule {
+        let map = VariableMap::new(schedule, variables, 1);
+        let solution = Solution::parse("init", &assignment.write_to_string()).unwrap();
+        DecodedSchedule::decode(&map, &solution).unwrap()
+    }
+
+    #[test]
+    fn serial_schedule_respects_the_resources() {
+        let (schedule, variables) = instance(3);
+        let assignment = InitialAssignment::new(&schedule);
+        assert_eq!(assignment.source, AssignmentSource::SerialSchedule);
+        assert!(assignment.is_solution(variables));
+        let decoded = decode(&schedule, variables, &assignment);
+        assert_eq!(validate(&schedule, &decoded), Vec::new());
+        // 2 and 3 have the same earliest start, so 2 goes first. The encoding counts the resources
+        // of 2 up to and including time 2, so 3 can only start at 3
+        let starts: Vec<Option<usize>> = decoded.projects.iter().map(|p| p.start).collect();
+        assert_eq!(starts, vec![Some(0), Some(0), Some(3), Some(4)]);
+        assert_eq!(assignment.makespan, 4);
+    }
+
+    #[test]
+    fn earliest_starts_without_room_for_the_resources() {
+        let (schedule, variables) = instance(1);
+        let assignment = InitialAssignment::new(&schedule);
+        assert_eq!(assignment.source, AssignmentSource::EarliestStarts);
+        assert!(!assignment.is_solution(variables));
+        let decoded = decode(&schedule, variables, &assignment);
+        let starts: Vec<Option<usize>> = decoded.projects.iter().map(|p| p.start).collect();
+        assert_eq!(starts, vec![Some(0), Some(0), Some(0), Some(1)]);
+    }
+}
@@ -1,56 +1,308 @@
+pub mod decoder;
+pub mod initial_assignment;
+pub mod reader;
+#[allow(non_snake_case)]
+pub mod readerRCP;
+#[allow(non_snake_case)]
 pub mod readerSM;
 pub mod schedule;
+pub mod validator;
+pub mod variable_map;
 
-use bumpalo::Bump;
-//use clap::Parser;
+use clap::{Parser, Subcommand};
+use decoder::ScheduleFormat;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use initial_assignment::InitialAssignment;
 use rayon::prelude::*;
+use reader::{InstanceFormat, ParseError};
 use schedule::Schedule;
 use shared::{
-    floyd_warshall,
+    heuristic_file::{HeuristicFile, VariableGroup, VariableHint},
     id_generator::IdGenerator,
-    sat_seg_var::{Clause, SATSVar, SATUVar},
-    segment::Segment,
+    pb_encoding::PBEncoding,
+    sat_seg_var::{CapacityConstraint, Clause, SATSVar, SATUVar},
 };
 use std::{
-    cell::RefCell,
-    env,
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
     fs::{self, read_dir},
-    path::Path,
-    rc::Rc,
+    iter::zip,
+    path::{Path, PathBuf},
+    str::FromStr,
 };
+use variable_map::VariableMap;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    // Path provided as the first argument
-    if args.len() <= 1 {
-        let path = String::from("data/test/j301_0.sm");
-        batch_file(&path);
+#[derive(Debug, Parser)]
+#[command(author, version, about, long_about = None, args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// The instance to encode, or a directory of instances to encode.
+    #[arg(default_value = "data/datasets/j30.sm")]
+    path: PathBuf,
+
+    /// The format of the instances: 'psplib' or 'patterson'. By default it is derived from the
+    /// extension of each file, '.sm' and '.mm' are read as PSPLIB and '.rcp' as Patterson.
+    #[arg(long)]
+    format: Option<InstanceFormat>,
+
+    /// The directory to write to. The file for instance 'j301_1' with set-up cost 2 is written
+    /// to '[OUTPUT]/2/2Fj301_1.wcnf', with its variable map '2Fj301_1.map.json', the
+    /// branching hints for pumpkin's `--heuristic-file` in '2Fj301_1.heur' and an initial
+    /// assignment next to it. If a serial schedule fits the resources and the resources use the
+    /// native encoding, the assignment is a solution and is written to '2Fj301_1.init' for
+    /// `--initial-solution` or `--phase-hints`. Otherwise it leaves out the variables of the
+    /// clausal encoding or ignores the resources, and is written to '2Fj301_1.phases' for
+    /// `--phase-hints` only.
+    #[arg(short = 'o', long = "output", default_value = "data/parsed/CP")]
+    output: PathBuf,
+
+    /// The set-up costs to generate a file for, given as a comma separated list ('0,2,5') or as
+    /// a range ('0..6' or '0..=5').
+    #[arg(short = 's', long = "set-up-costs", default_value = "0..6", value_parser = set_up_costs_parser)]
+    set_up_costs: SetUpCosts,
+
+    /// What to do with output files that already exist: 'skip' or 'overwrite'. With 'skip', an
+    /// instance is generated again if any of its files is missing.
+    #[arg(long = "existing", default_value_t = ExistingFilePolicy::Skip)]
+    existing: ExistingFilePolicy,
+
+    /// How the renewable and nonrenewable resource constraints are written: as clauses using the
+    /// 'swc', 'bdd' or 'adder' encoding, or as 'native' capacity constraint lines for pumpkin.
+    #[arg(long = "resource-encoding", default_value_t = ResourceEncoding::default())]
+    resource_encoding: ResourceEncoding,
+
+    /// The number of files to generate in parallel. By default there is one thread per core.
+    #[arg(short = 'j', long = "threads")]
+    threads: Option<usize>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Turn the output of a solver on a generated file back into a schedule.
+    Decode(DecodeArgs),
+    /// Check the schedule found by a solver against the original instance. Exits with 1 if the
+    /// schedule violates the instance, and with 2 if it could not be checked.
+    Validate(ValidateArgs),
+}
+
+#[derive(Debug, clap::Args)]
+struct DecodeArgs {
+    /// The generated file the solver was run on. Its variable map is read from next to it, for
+    /// '2Fj301_1.wcnf' that is '2Fj301_1.map.json'.
+    instance: PathBuf,
+
+    /// The output of the solver, containing the 'v' line of the solution.
+    solution: PathBuf,
+
+    /// The format to write the schedule in: 'json' or 'csv'.
+    #[arg(long = "schedule-format", default_value_t = ScheduleFormat::Json)]
+    schedule_format: ScheduleFormat,
+
+    /// The file to write the schedule to. By default it is printed.
+    #[arg(short = 'o', long = "output")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Args)]
+struct ValidateArgs {
+    /// The instance the file was generated from.
+    instance: PathBuf,
+
+    /// The generated file the solver was run on, with its variable map next to it.
+    generated: PathBuf,
+
+    /// The output of the solver, containing the 'v' line of the solution.
+    solution: PathBuf,
+
+    /// The format of the instance. By default it is derived from the extension.
+    #[arg(long)]
+    format: Option<InstanceFormat>,
+}
+
+/// The set-up costs to generate files for, in the order they were given
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SetUpCosts(Vec<usize>);
+
+fn set_up_costs_parser(s: &str) -> Result<SetUpCosts, String> {
+    let parse = |value: &str| {
+        value
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| format!("'{value}' is not a valid set-up cost"))
+    };
+    let costs = if let Some((start, end)) = s.split_once("..=") {
+        (parse(start)?..=parse(end)?).collect()
+    } else if let Some((start, end)) = s.split_once("..") {
+        (parse(start)?..parse(end)?).collect()
     } else {
-        let path_string = &args[1];
-        // Turn into dir
-        let dir_check = Path::new(path_string);
-        // Single file option
-        if dir_check.is_file() {
-            batch_file(dir_check.to_str().unwrap());
+        s.split(',')
+            .map(parse)
+            .collect::<Result<Vec<usize>, String>>()?
+    };
+    if costs.is_empty() {
+        return Err(format!("'{s}' does not contain any set-up cost"));
+    }
+    // Every cost is written to the same files, so a repeated one would be generated twice at once
+    if let Some(cost) = costs
+        .iter()
+        .enumerate()
+        .find_map(|(index, cost)| costs[..index].contains(cost).then_some(cost))
+    {
+        return Err(format!("set-up cost {cost} is given more than once"));
+    }
+    Ok(SetUpCosts(costs))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExistingFilePolicy {
+    /// Keep the existing files, so an interrupted run can be resumed. An instance is only skipped
+    /// if all of its files exist, so files added by newer versions are still written
+    Skip,
+    Overwrite,
+}
+
+impl FromStr for ExistingFilePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(ExistingFilePolicy::Skip),
+            "overwrite" => Ok(ExistingFilePolicy::Overwrite),
+            value => Err(format!(
+                "'{value}' is not a valid policy for existing files"
+            )),
         }
-        // Entire directory given
-        else if dir_check.is_dir() {
-            let dir = read_dir(dir_check).unwrap();
-            for file in dir.into_iter() {
-                let file_path = file.unwrap().path();
-                let file_name = file_path.to_str().unwrap();
+    }
+}
 
-                batch_file(file_name)
-            }
-        } else {
-            println!(
-                "Argument provided: [{:?}], but not path or directory :(",
-                path_string
+impl Display for ExistingFilePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExistingFilePolicy::Skip => write!(f, "skip"),
+            ExistingFilePolicy::Overwrite => write!(f, "overwrite"),
+        }
+    }
+}
+
+fn main() {
+    let args = Args::parse();
+    match &args.command {
+        Some(Command::Decode(decode_args)) => return decode(decode_args),
+        Some(Command::Validate(validate_args)) => return validate(validate_args),
+        None => {}
+    }
+    let dir_check = args.path.as_path();
+    let files: Vec<String> = if dir_check.is_file() {
+        // Single file option
+        vec![dir_check.to_str().unwrap().to_owned()]
+    } else if dir_check.is_dir() {
+        // Entire directory given
+        read_dir(dir_check)
+            .unwrap()
+            .map(|file| file.unwrap().path().to_str().unwrap().to_owned())
+            .collect()
+    } else {
+        println!(
+            "Argument provided: [{:?}], but not path or directory :(",
+            dir_check
+        );
+        return;
+    };
+    // Files we do not know how to read are left alone
+    let files: Vec<(String, InstanceFormat)> = files
+        .into_iter()
+        .filter_map(|file| {
+            let format = args
+                .format
+                .or_else(|| InstanceFormat::from_extension(get_ending(&file)))?;
+            Some((file, format))
+        })
+        .collect();
+    // The output is named after the file without its extension, so 'small.sm' and 'small.rcp'
+    // would overwrite each other's files
+    let mut sources: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (file, _) in files.iter() {
+        sources.entry(strip_ending(file)).or_default().push(file);
+    }
+    let clashes: Vec<(&str, Vec<&str>)> = sources
+        .into_iter()
+        .filter(|(_, files)| files.len() > 1)
+        .collect();
+    if !clashes.is_empty() {
+        for (name, mut files) in clashes {
+            files.sort();
+            eprintln!(
+                "{} would be written to the same files, named after '{}'. Rename all but one of them",
+                files.join(", "),
+                name
             );
         }
+        std::process::exit(1);
+    }
+
+    if let Some(threads) = args.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .expect("The thread pool is only built once");
+    }
+    let progress = MultiProgress::new();
+    let overall = progress.add(ProgressBar::new(
+        (files.len() * args.set_up_costs.0.len()) as u64,
+    ));
+    overall.set_style(
+        ProgressStyle::with_template("{bar:40} {pos}/{len} files generated [{elapsed_precise}]")
+            .unwrap(),
+    );
+    files
+        .par_iter()
+        .for_each(|(file, format)| batch_file(file, *format, &args, &progress, &overall));
+    overall.finish();
+}
+fn decode(args: &DecodeArgs) {
+    let schedule = match decoder::decode_file(&args.instance, &args.solution) {
+        Ok(schedule) => schedule,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    };
+    let output = schedule.write_to_string(args.schedule_format);
+    match &args.output {
+        Some(path) => fs::write(path, output).expect("Unable to write"),
+        None => print!("{}", output),
     }
 }
+fn validate(args: &ValidateArgs) {
+    let instance = args.instance.to_str().unwrap();
+    let Some(format) = args
+        .format
+        .or_else(|| InstanceFormat::from_extension(get_ending(instance)))
+    else {
+        eprintln!("Cannot tell the format of {}, use --format", instance);
+        std::process::exit(2);
+    };
+    let instance = read_file(instance, format).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        std::process::exit(2);
+    });
+    let schedule = decoder::decode_file(&args.generated, &args.solution).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        std::process::exit(2);
+    });
+    let violations = validator::validate(&instance, &schedule);
+    if violations.is_empty() {
+        println!("Valid schedule with makespan {}", schedule.makespan);
+        return;
+    }
+    for violation in violations.iter() {
+        println!("{}", violation);
+    }
+    println!("{} violations", violations.len());
+    std::process::exit(1);
+}
 fn strip_ending(file_name: &str) -> &str {
     file_name
         .split('/')
@@ -69,43 +321,74 @@ fn get_ending(file_name: &str) -> &str {
         .last()
         .unwrap()
 }
-fn batch_file(file: &str) {
-    if get_ending(file) != "sm" && get_ending(file) != "mm" {
-        return;
-    }
-    let arena = Bump::new();
-    let schedule = read_file(file, &arena);
+fn batch_file(
+    file: &str,
+    format: InstanceFormat,
+    args: &Args,
+    progress: &MultiProgress,
+    overall: &ProgressBar,
+) {
+    let schedule = match read_file(file, format) {
+        Ok(schedule) => schedule,
+        Err(error) => {
+            // One bad file should not abort a run over a whole directory
+            progress.suspend(|| eprintln!("Skipping {}: {}", file, error));
+            overall.inc(args.set_up_costs.0.len() as u64);
+            return;
+        }
+    };
 
-    let bar = ProgressBar::new(6);
-    for set_up_time in 0..6 {
-        bar.set_message(
-            "Generating next file for set up time: ".to_owned() + &set_up_time.to_string(),
-        );
+    args.set_up_costs.0.par_iter().for_each(|set_up_time| {
         // this is the file name that will be created
-        let destination = &[
-            "data/parsed/CP/",
-            &set_up_time.to_string(),
-            "/",
-            &(set_up_time).to_string(),
-            "F",
-            strip_ending(file),
-            ".wcnf",
-        ]
-        .concat();
-
-        if Path::new(destination).exists() {
-            continue;
+        let destination = args
+            .output
+            .join(set_up_time.to_string())
+            .join([&set_up_time.to_string(), "F", strip_ending(file), ".wcnf"].concat());
+
+        if args.existing == ExistingFilePolicy::Skip && is_complete(&destination) {
+            overall.inc(1);
+            return;
+        }
+        if let Err(error) = fs::create_dir_all(destination.parent().unwrap_or(&args.output)) {
+            progress.suspend(|| {
+                eprintln!(
+                    "Could not create the directory for {:?}: {}",
+                    destination, error
+                )
+            });
+            overall.inc(1);
+            return;
         }
-        fs::create_dir_all(
-            Path::new(destination)
-                .parent()
-                .unwrap_or(Path::new(destination)),
-        );
 
-        write_file(&schedule, 1, destination);
-        bar.inc(1);
-    }
-    bar.finish();
+        let bar = progress.add(ProgressBar::new_spinner());
+        bar.set_style(ProgressStyle::with_template("{spinner} {prefix}: {wide_msg}").unwrap());
+        bar.set_prefix(destination.to_str().unwrap().to_owned());
+        // Every job adds its own set-up cost and variables, so it works on its own copy
+        write_file(
+            schedule.clone(),
+            *set_up_time,
+            destination.to_str().unwrap(),
+            args.resource_encoding,
+            &bar,
+        );
+        bar.finish_and_clear();
+        progress.remove(&bar);
+        overall.inc(1);
+    });
+}
+/// Whether every file for the instance at `destination` exists, the '.wcnf' itself included. The
+/// initial assignment is either a '.init' or a '.phases' file, depending on whether it is a
+/// solution
+fn is_complete(destination: &Path) -> bool {
+    let files = [
+        destination.to_path_buf(),
+        VariableMap::path_for(destination),
+        HeuristicFile::path_for(destination),
+    ];
+    files.iter().all(|file| file.exists())
+        && [true, false]
+            .iter()
+            .any(|is_solution| InitialAssignment::path_for(destination, *is_solution).exists())
 }
 fn get_sat_dir(file: &str) -> &str {
     ""
@@ -113,115 +396,362 @@ fn get_sat_dir(file: &str) -> &str {
 fn create_solved_dir(file: &str) -> &str {
     ""
 }
-fn read_file<'a>(file: &'a str, arena: &'a Bump) -> Schedule<'a> {
-    println!("Reading from: {:?}", file);
-    let schedule = readerSM::read_input(file, arena).unwrap();
-    schedule
+fn read_file(file: &str, format: InstanceFormat) -> Result<Schedule, ParseError> {
+    format.reader().read_input(file)
 }
 
-fn write_file(schedule: &Schedule, set_up_addition: usize, destination: &str) {
-    let mut segments: Vec<Rc<RefCell<Segment>>> = Vec::new();
-    let mut horizon = 0;
+/// How the resource constraints end up in the generated file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceEncoding {
+    /// Encoded into hard clauses, which any MaxSAT solver can read
+    Clauses(PBEncoding),
+    /// Written as capacity constraint lines, which pumpkin handles with a propagator
+    Native,
+}
 
-    for project in schedule.projects.iter() {
-        horizon += project.duration();
-        for segment in project.segments() {
-            // we are adding, and this is a side effect
-            segment.borrow_mut().add_set_up_time(set_up_addition);
-            segments.push(segment.clone());
+impl Default for ResourceEncoding {
+    fn default() -> Self {
+        ResourceEncoding::Clauses(PBEncoding::default())
+    }
+}
+
+impl FromStr for ResourceEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "native" => Ok(ResourceEncoding::Native),
+            value => value.parse().map(ResourceEncoding::Clauses).map_err(|_| {
+                format!(
+                    "'{value}' is not a valid resource encoding. Possible values: ['swc', 'bdd', 'adder', 'native']"
+                )
+            }),
         }
     }
-    // sort by id
-    segments.sort_by_key(|k| k.borrow().id());
+}
 
-    let distances = floyd_warshall::segments_dist_shortest_vec(&segments);
-    let critical_path = floyd_warshall::segments_dist_longest_vec(&segments)
-        .iter()
-        .flatten()
-        .min()
-        .unwrap()
-        .unsigned_abs() as usize;
-    println!("Floyd Warshall called");
+impl Display for ResourceEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResourceEncoding::Clauses(encoding) => write!(f, "{}", encoding),
+            ResourceEncoding::Native => write!(f, "native"),
+        }
+    }
+}
+
+fn write_file(
+    mut schedule: Schedule,
+    set_up_addition: usize,
+    destination: &str,
+    resource_encoding: ResourceEncoding,
+    bar: &ProgressBar,
+) {
+    let graph = &mut schedule.graph;
+    for segment in graph.segments_mut() {
+        segment.add_set_up_time(set_up_addition);
+    }
+
+    bar.set_message("Computing distances");
+    let early_starts = graph.earliest_starts();
+    let critical_path = graph.critical_path();
     let mut id_gen = IdGenerator::generator_for_sat();
 
+    bar.set_message("Generating clauses");
+    // All variables have to exist before a segment can refer to the ones of its predecessors
+    for (segment, early_start) in zip(graph.segments_mut(), early_starts.iter()) {
+        segment.generate_SAT_vars(&mut id_gen, *early_start, critical_path);
+    }
     let mut clauses: Vec<Clause> = Vec::new();
-    for segment in segments.iter() {
-        let early_start = distances[segment.borrow().id()][0].unsigned_abs() as usize;
-
-        segment
-            .borrow_mut()
-            .generate_SAT_vars(&mut id_gen, early_start, critical_path);
-        for clause in segment.borrow().generate_precedence_clauses() {
-            clauses.push(clause);
-        }
-        for clause in segment.borrow().generate_consistency_clause() {
-            clauses.push(clause);
-        }
+    for id in 0..graph.len() {
+        clauses.append(&mut graph.generate_precedence_clauses(id));
+        clauses.append(&mut graph.segment(id).generate_consistency_clause());
     }
 
+    for project in schedule.projects.iter_mut() {
+        project.generate_mode_vars(&mut id_gen);
+        clauses.append(&mut project.generate_mode_clauses(graph));
+        clauses.append(&mut project.generate_completion_clauses(graph));
+    }
+    let graph = &schedule.graph;
+    let mut u_vars: Vec<&SATUVar> = Vec::new();
+    let mut s_vars: Vec<&SATSVar> = Vec::new();
     for project in schedule.projects.iter() {
-        for clause in project.generate_completion_clauses() {
-            clauses.push(clause);
+        for segment in project.segments().iter().map(|id| graph.segment(*id)) {
+            u_vars.extend(segment.uvariables.iter());
+            s_vars.extend(segment.variables.iter());
         }
     }
-    println!("Clause generation done");
-    let mut u_vars: Vec<Rc<SATUVar>> = Vec::new();
-    let mut s_vars: Vec<Rc<SATSVar>> = Vec::new();
-    for project in schedule.projects.iter() {
-        for segment in project.segments().iter() {
-            for u_var in segment.borrow().uvariables.borrow().iter() {
-                u_vars.push(Rc::clone(u_var));
+    bar.set_message("Encoding resource constraints");
+    let mut capacity_constraints: Vec<CapacityConstraint> = Vec::new();
+    match resource_encoding {
+        ResourceEncoding::Clauses(encoding) => {
+            let mut resource_clauses =
+                Clause::u_vec_accum(u_vars, &mut id_gen, schedule.resources.clone(), encoding);
+            clauses.append(&mut resource_clauses);
+        }
+        ResourceEncoding::Native => {
+            capacity_constraints = Clause::u_vec_capacity(u_vars, schedule.resources.clone());
+        }
+    }
+    bar.set_message("Encoding nonrenewable budgets");
+    for (index, budget) in schedule.nonrenewable.iter().enumerate() {
+        // Nonrenewable usage is stored after the renewable usage of a mode
+        let resource_index = schedule.resources.len() + index;
+        // Whatever a project uses in its cheapest mode is spent regardless of the selected mode
+        let mut fixed_usage = 0;
+        let mut terms: Vec<(usize, usize)> = Vec::new();
+        for project in schedule.projects.iter() {
+            let usage: Vec<usize> = project
+                .modes()
+                .iter()
+                .map(|mode| mode.resource[resource_index])
+                .collect();
+            let cheapest = *usage.iter().min().unwrap();
+            fixed_usage += cheapest;
+            for (mode_variable, mode_usage) in zip(project.mode_variables().iter().copied(), usage)
+            {
+                if mode_usage > cheapest {
+                    terms.push((mode_usage - cheapest, mode_variable));
+                }
             }
-            for s_var in segment.borrow().variables.borrow().iter() {
-                s_vars.push(Rc::clone(s_var));
+        }
+        if fixed_usage > *budget {
+            bar.suspend(|| {
+                eprintln!(
+                    "{}: nonrenewable resource {} is over budget in every mode assignment",
+                    destination, index
+                )
+            });
+            // Make the file unsatisfiable rather than silently dropping the budget
+            let conflict = id_gen.next_id() as i64;
+            clauses.push(Clause::new(vec![conflict]));
+            clauses.push(Clause::new(vec![-conflict]));
+            continue;
+        }
+        let capacity = budget - fixed_usage;
+        match resource_encoding {
+            ResourceEncoding::Clauses(encoding) => {
+                let lits: Vec<i64> = terms.iter().map(|(_, id)| *id as i64).collect();
+                let weights: Vec<usize> = terms.iter().map(|(weight, _)| *weight).collect();
+                let mut budget_clauses =
+                    encoding.encode_at_most(&lits, &weights, capacity, &mut id_gen);
+                clauses.append(&mut budget_clauses);
+            }
+            ResourceEncoding::Native => {
+                if terms.iter().map(|(weight, _)| weight).sum::<usize>() > capacity {
+                    capacity_constraints.push(CapacityConstraint::new(
+                        resource_index,
+                        capacity,
+                        terms,
+                    ));
+                }
             }
         }
     }
-    println!("Calling python");
-    let mut resource_clauses = Clause::u_vec_accum(u_vars, &mut id_gen, schedule.resources.clone());
-    clauses.append(&mut resource_clauses);
     s_vars.sort_by_key(|e| (e.weight(), e.time(), -(e.segment_duration() as i64)));
-    let mut s_order: Vec<usize> = s_vars.iter().map(|s| s.id()).collect();
     // Soft clause (maxspan) generation (and the thing we measure at the end)
     let last = schedule.projects.last().unwrap();
-    println!("Generating clauses for final variabel");
-    let mut last_svars: Vec<Rc<SATSVar>> = Vec::new();
-    for segment in last.segments().iter() {
-        for variable in segment.borrow().variables.borrow().iter() {
-            last_svars.push(Rc::clone(variable));
-        }
-    }
+    bar.set_message("Generating clauses for final variabel");
+    let mut last_svars: Vec<&SATSVar> = last
+        .segments()
+        .iter()
+        .flat_map(|id| graph.segment(*id).variables.iter())
+        .collect();
     last_svars.sort_by_key(|u| u.time());
-    let min_end = distances[last.id()][0].unsigned_abs() as usize;
+    let min_end = last
+        .segments()
+        .iter()
+        .map(|id| early_starts[*id])
+        .min()
+        .unwrap();
     let weights: Vec<usize> = last_svars.iter().map(|x| x.time()).collect();
     let weight = weights.iter().max().unwrap() + 1;
     //let weights: Vec<usize> = (1 + min_end..weight + 1 + min_end).rev().collect();
     let last_s_clause = SATSVar::last_to_clause(&last_svars);
 
-    let mut unused: Vec<usize> = (1..id_gen.current_asignment() + 1)
-        .filter(|a| !s_order.contains(a))
-        .collect();
-    s_order.append(&mut unused);
-
-    println!("Writing to: {}", destination);
+    bar.set_message("Writing");
+    let heuristic = heuristic_file(&schedule, &s_vars, id_gen.current_asignment());
+    fs::write(
+        HeuristicFile::path_for(Path::new(destination)),
+        heuristic.write_to_string(),
+    )
+    .expect("Unable to write");
+    let assignment = InitialAssignment::new(&schedule);
+    fs::write(
+        InitialAssignment::path_for(
+            Path::new(destination),
+            assignment.is_solution(id_gen.current_asignment()),
+        ),
+        assignment.write_to_string(),
+    )
+    .expect("Unable to write");
+    let map = VariableMap::new(&schedule, id_gen.current_asignment(), set_up_addition);
+    fs::write(
+        VariableMap::path_for(Path::new(destination)),
+        serde_json::to_string(&map).expect("A variable map can always be serialised"),
+    )
+    .expect("Unable to write");
     fs::write(
         destination,
-        first_line(s_order)
-            + &Clause::write_first_line(
-                clauses.len() + last_s_clause.len(),
-                id_gen.current_asignment(),
-                weight + min_end,
-            )
+        Clause::write_first_line(
+            clauses.len() + last_s_clause.len(),
+            id_gen.current_asignment(),
+            weight + min_end,
+        ) + &CapacityConstraint::write_list_to_string(&capacity_constraints)
             + &Clause::write_list_to_string_hard(clauses, weight + min_end)
             + &Clause::write_list_to_string_soft(last_s_clause, weights),
     )
     .expect("Unable to write");
 }
-pub fn first_line(vec: Vec<usize>) -> String {
-    "c ".to_string() + &vec.iter().map(|x| x.to_string() + " ").collect::<String>() + "\n"
+/// Branches on the start variables in the order they are sorted in, trying to start each segment
+/// at the time first. The remaining variables come after them without a preferred polarity.
+fn heuristic_file(schedule: &Schedule, s_vars: &[&SATSVar], variables: usize) -> HeuristicFile {
+    let graph = &schedule.graph;
+    let groups = schedule
+        .projects
+        .iter()
+        .flat_map(|project| project.segments().iter().map(|id| graph.segment(*id)))
+        .filter(|segment| !segment.variables.is_empty())
+        .map(|segment| {
+            let times = segment.variables.iter().map(|s_var| s_var.time());
+            VariableGroup {
+                id: segment.id(),
+                metadata: vec![
+                    ("project".to_owned(), segment.parent_project.to_string()),
+                    ("duration".to_owned(), segment.duration().to_string()),
+                    (
+                        "earliest".to_owned(),
+                        times.clone().min().unwrap().to_string(),
+                    ),
+                    ("latest".to_owned(), times.max().unwrap().to_string()),
+                ],
+            }
+        })
+        .collect();
+    let start_variables: HashSet<usize> = s_vars.iter().map(|s_var| s_var.id()).collect();
+    let hints = s_vars
+        .iter()
+        .map(|s_var| (s_var.id(), Some(true), Some(s_var.segment_id())))
+        .chain(
+            (1..variables + 1)
+                .filter(|id| !start_variables.contains(id))
+                .map(|id| (id, None, None)),
+        )
+        .enumerate()
+        .map(|(position, (variable, polarity, group))| VariableHint {
+            variable,
+            priority: (variables - position) as u64,
+            polarity,
+            group,
+        })
+        .collect();
+    HeuristicFile {
+        groups,
+        variables: hints,
+    }
 }
 #[cfg(test)]
 mod test {
+    use crate::{heuristic_file, readerSM::create_projects, schedule::Schedule};
+    use crate::{set_up_costs_parser, SetUpCosts};
+    use shared::{id_generator::IdGenerator, project::Mode, sat_seg_var::SATSVar};
+
     #[test]
     fn counterExampleFileProd() {}
+
+    #[test]
+    fn set_up_costs_from_list_or_range() {
+        assert_eq!(set_up_costs_parser("0,2,5"), Ok(SetUpCosts(vec![0, 2, 5])));
+        assert_eq!(set_up_costs_parser("3"), Ok(SetUpCosts(vec![3])));
+        assert_eq!(set_up_costs_parser("1..4"), Ok(SetUpCosts(vec![1, 2, 3])));
+        assert_eq!(
+            set_up_costs_parser("1..=4"),
+            Ok(SetUpCosts(vec![1, 2, 3, 4]))
+        );
+        assert!(set_up_costs_parser("4..1").is_err());
+        assert!(set_up_costs_parser("one").is_err());
+        assert!(set_up_costs_parser("1,2,1").is_err());
+    }
+
+    #[test]
+    fn heuristic_file_orders_the_start_variables_first() {
+        // 1 -> 2 -> 3, with room for 2 to start one step late
+        let modes = vec![
+            vec![Mode::new(0, vec![0])],
+            vec![Mode::new(2, vec![1])],
+            vec![Mode::new(0, vec![0])],
+        ];
+        let successors = vec![vec![2], vec![3], vec![]];
+        let (projects, mut graph) = create_projects(modes, successors, vec![1, 2, 3]);
+        let early_starts = graph.earliest_starts();
+        let horizon = graph.critical_path() + 1;
+        let mut id_gen = IdGenerator::generator_for_sat();
+        for (segment, early_start) in graph.segments_mut().iter_mut().zip(early_starts) {
+            segment.generate_SAT_vars(&mut id_gen, early_start, horizon);
+        }
+        let schedule = Schedule::new(projects, graph, vec![1], Vec::new());
+        let variables = id_gen.current_asignment();
+
+        // The order of the start variables is kept, whatever it is
+        let mut s_vars: Vec<&SATSVar> = schedule
+            .graph
+            .segments()
+            .iter()
+            .flat_map(|segment| segment.variables.iter())
+            .collect();
+        s_vars.reverse();
+        let heuristic = heuristic_file(&schedule, &s_vars, variables);
+
+        let hints = &heuristic.variables;
+        assert_eq!(hints.len(), variables);
+        let priorities: Vec<u64> = hints.iter().map(|hint| hint.priority).collect();
+        assert_eq!(
+            priorities,
+            (1..variables as u64 + 1).rev().collect::<Vec<u64>>()
+        );
+        for (hint, s_var) in hints.iter().zip(&s_vars) {
+            assert_eq!(hint.variable, s_var.id());
+            assert_eq!(hint.polarity, Some(true));
+            assert_eq!(hint.group, Some(s_var.segment_id()));
+        }
+        let rest = &hints[s_vars.len()..];
+        let others: Vec<usize> = (1..variables + 1)
+            .filter(|id| s_vars.iter().all(|s_var| s_var.id() != *id))
+            .collect();
+        let rest_variables: Vec<usize> = rest.iter().map(|hint| hint.variable).collect();
+        assert_eq!(rest_variables, others);
+        assert!(rest
+            .iter()
+            .all(|hint| hint.polarity.is_none() && hint.group.is_none()));
+
+        // Every segment has start variables here, each of which is described by a group
+        assert_eq!(heuristic.groups.len(), schedule.graph.segments().len());
+        // Project 2 can be split into parts, the segment that runs it in one go lasts 2
+        let middle = schedule.projects[1]
+            .segments()
+            .iter()
+            .map(|id| schedule.graph.segment(*id))
+            .find(|segment| segment.duration() == 2)
+            .unwrap();
+        let group = heuristic
+            .groups
+            .iter()
+            .find(|group| group.id == middle.id())
+            .unwrap();
+        let times: Vec<usize> = middle.variables.iter().map(|s_var| s_var.time()).collect();
+        let metadata: Vec<(&str, String)> = group
+            .metadata
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.clone()))
+            .collect();
+        assert_eq!(
+            metadata,
+            vec![
+                ("project", middle.parent_project.to_string()),
+                ("duration", "2".to_owned()),
+                ("earliest", times.iter().min().unwrap().to_string()),
+                ("latest", times.iter().max().unwrap().to_string()),
+            ]
+        );
+    }
 }
@@ -1,95 +1,104 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
+use crate::{readerRCP::PattersonReader, readerSM::PsplibReader, schedule::Schedule};
+use std::{fmt::Display, str::FromStr};
+use thiserror::Error;
 
-pub fn read_input(filename: &str) -> Option<()> {
-    let file = File::open(filename).expect(format!("Could not read file {}", filename).as_str());
-    let mut lines = BufReader::new(file).lines();
+/// Reads an instance file into a [`Schedule`]
+pub trait InstanceReader {
+    fn read_input(&self, filename: &str) -> Result<Schedule, ParseError>;
+}
 
-    skip_lines(&mut lines, 5);
-    let n_activities = get_number_of_activities(lines.next()?.ok()?);
-    skip_lines(&mut lines, 2);
-    let n_renewable = get_number_of_resources(lines.next()?.ok()?);
-    let n_nonrenewable = get_number_of_resources(lines.next()?.ok()?);
-    skip_lines(&mut lines, 8);
+/// Why an instance file could not be read, line numbers start at 1
+#[derive(Error, Debug)]
+pub enum ParseError {
+    #[error("Failed to read file {file}, more details: {source}")]
+    Io {
+        file: String,
+        source: std::io::Error,
+    },
+    #[error("{file}:{line}: missing field '{field}'")]
+    MissingField {
+        file: String,
+        line: usize,
+        field: &'static str,
+    },
+    #[error("{file}:{line}: missing section '{section}'")]
+    MissingSection {
+        file: String,
+        line: usize,
+        section: &'static str,
+    },
+    #[error("{file}:{line}: section '{section}' ended early")]
+    UnexpectedEndOfSection {
+        file: String,
+        line: usize,
+        section: &'static str,
+    },
+    #[error("{file}:{line}: unexpected end of file")]
+    UnexpectedEndOfFile { file: String, line: usize },
+    #[error("{file}:{line}: '{token}' is not a valid number")]
+    InvalidNumber {
+        file: String,
+        line: usize,
+        token: String,
+    },
+    #[error("{file}:{line}: successor {successor} is not a job")]
+    UnknownSuccessor {
+        file: String,
+        line: usize,
+        successor: usize,
+    },
+    #[error("{file}:{line}: {message}")]
+    Malformed {
+        file: String,
+        line: usize,
+        message: String,
+    },
+}
 
-    let activities: Vec<usize> = (0..n_activities).collect();
-    let mut modes: Vec<usize> = Vec::new();
-    let mut modes_per_activity: Vec<Vec<usize>> = vec![Default::default(); n_activities];
-    let mut successors: Vec<Vec<usize>> = vec![Default::default(); n_activities];
+/// The file formats we can read instances from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstanceFormat {
+    /// PSPLIB files, both single mode (.sm) and multi-mode (.mm)
+    Psplib,
+    /// Patterson files (.rcp), used by the RG30, RG300 and CV sets
+    Patterson,
+}
 
-    for i in 0..n_activities {
-        let line = line_to_numbers(lines.next()?.ok()?);
-        let n_modes = *line.get(1).unwrap();
-        let modes_i = modes_per_activity.get_mut(i).unwrap();
-        for _ in 0..n_modes {
-            modes_i.push(modes.len());
-            modes.push((modes.len()));
-        }
-        if line.len() > 3 {
-            let successors_i = successors.get_mut(i).unwrap();
-            for j in 3..line.len() {
-                successors_i.push((line.get(j).unwrap() - 1) as usize);
-            }
+impl InstanceFormat {
+    /// Guesses the format from the extension of a file, if it is one we know
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "sm" | "mm" => Some(InstanceFormat::Psplib),
+            "rcp" => Some(InstanceFormat::Patterson),
+            _ => None,
         }
     }
 
-    let mut durations_per_mode: Vec<u32> = Vec::with_capacity(modes.len());
-    let mut resources_per_mode: Vec<Vec<u32>> =
-        vec![vec![Default::default(); n_renewable + n_nonrenewable]; modes.len()];
-
-    skip_lines(&mut lines, 4);
-    for mode in 0..modes.len() {
-        let line = line_to_numbers(lines.next()?.ok()?);
-        durations_per_mode.push(
-            *line
-                .get(line.len() - (n_renewable + n_nonrenewable + 1))
-                .unwrap(),
-        );
-        let resources_mode = resources_per_mode.get_mut(mode).unwrap();
-        for i in 0..n_renewable {
-            resources_mode[i] = *line
-                .get(line.len() + i - n_nonrenewable - n_renewable)
-                .unwrap();
-        }
-        for i in n_renewable..(n_renewable + n_nonrenewable) {
-            resources_mode[i] = *line
-                .get(line.len() + i - n_nonrenewable - n_renewable)
-                .unwrap();
+    pub fn reader(&self) -> Box<dyn InstanceReader> {
+        match self {
+            InstanceFormat::Psplib => Box::new(PsplibReader),
+            InstanceFormat::Patterson => Box::new(PattersonReader),
         }
     }
-
-    skip_lines(&mut lines, 3);
-    let line = line_to_numbers(lines.next()?.ok()?);
-    let capacity_per_renewable_resource = line.get(..n_renewable).unwrap().to_vec();
-    let capacity_per_nonrenewable_resource = line.get(n_renewable..).unwrap().to_vec();
-
-    Some(())
-}
-
-fn skip_lines(lines: &mut Lines<BufReader<File>>, n: u32) {
-    for _ in 0..n {
-        lines.next();
-    }
 }
 
-fn get_number_of_activities(line: String) -> usize {
-    line.split(":").nth(1).unwrap().trim().parse().unwrap()
-}
+impl FromStr for InstanceFormat {
+    type Err = String;
 
-fn get_number_of_resources(line: String) -> usize {
-    line.split(":")
-        .nth(1)
-        .unwrap()
-        .trim()
-        .split_whitespace()
-        .nth(0)
-        .unwrap()
-        .parse()
-        .unwrap()
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "psplib" => Ok(InstanceFormat::Psplib),
+            "patterson" => Ok(InstanceFormat::Patterson),
+            value => Err(format!("'{value}' is not a valid instance format")),
+        }
+    }
 }
 
-fn line_to_numbers(line: String) -> Vec<u32> {
-    line.split_whitespace()
-        .map(|s| s.parse().unwrap())
-        .collect()
+impl Display for InstanceFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InstanceFormat::Psplib => write!(f, "psplib"),
+            InstanceFormat::Patterson => write!(f, "patterson"),
+        }
+    }
 }
@@ -0,0 +1,109 @@
+use crate::reader::{InstanceReader, ParseError};
+use crate::readerSM::create_projects;
+use crate::schedule::Schedule;
+use shared::project::Mode;
+use std::fs;
+
+/// Reader for the Patterson format. The file is a stream of numbers:
+/// [activities] [resources]
+/// [capacity_1] ... [capacity_k]
+/// and then for every activity, starting with the dummy source:
+/// [duration] [usage_1] ... [usage_k] [successors] [successor_1] ... [successor_n]
+pub struct PattersonReader;
+
+impl InstanceReader for PattersonReader {
+    fn read_input(&self, filename: &str) -> Result<Schedule, ParseError> {
+        let contents = fs::read_to_string(filename).map_err(|source| ParseError::Io {
+            file: filename.to_owned(),
+            source,
+        })?;
+        parse(filename, &contents)
+    }
+}
+
+/// The numbers of a file in order, remembering the line they were on
+struct Numbers<'f, I: Iterator<Item = (usize, &'f str)>> {
+    file: &'f str,
+    tokens: I,
+    line: usize,
+}
+
+impl<'f, I: Iterator<Item = (usize, &'f str)>> Numbers<'f, I> {
+    fn next(&mut self) -> Result<usize, ParseError> {
+        let (line, token) = self
+            .tokens
+            .next()
+            .ok_or_else(|| ParseError::UnexpectedEndOfFile {
+                file: self.file.to_owned(),
+                line: self.line,
+            })?;
+        self.line = line;
+        token.parse().map_err(|_| ParseError::InvalidNumber {
+            file: self.file.to_owned(),
+            line,
+            token: token.to_owned(),
+        })
+    }
+
+    fn next_n(&mut self, n: usize) -> Result<Vec<usize>, ParseError> {
+        (0..n).map(|_| self.next()).collect()
+    }
+}
+
+fn parse(filename: &str, contents: &str) -> Result<Schedule, ParseError> {
+    let mut numbers = Numbers {
+        file: filename,
+        tokens: contents
+            .lines()
+            .enumerate()
+            .flat_map(|(index, line)| line.split_whitespace().map(move |token| (index + 1, token))),
+        line: 1,
+    };
+
+    let n_activities = numbers.next()?;
+    let n_resources = numbers.next()?;
+    let capacities = numbers.next_n(n_resources)?;
+
+    let activities: Vec<usize> = (1..n_activities + 1).collect();
+    let mut modes: Vec<Vec<Mode>> = Vec::with_capacity(n_activities);
+    let mut successors: Vec<Vec<usize>> = Vec::with_capacity(n_activities);
+    for _ in 0..n_activities {
+        let duration = numbers.next()?;
+        let resource = numbers.next_n(n_resources)?;
+        let n_successors = numbers.next()?;
+        let successors_i = numbers.next_n(n_successors)?;
+        if let Some(successor) = successors_i.iter().find(|s| **s == 0 || **s > n_activities) {
+            return Err(ParseError::UnknownSuccessor {
+                file: filename.to_owned(),
+                line: numbers.line,
+                successor: *successor,
+            });
+        }
+        modes.push(vec![Mode::new(duration, resource)]);
+        successors.push(successors_i);
+    }
+    // Patterson instances have no nonrenewable resources
+    let (projects, graph) = create_projects(modes, successors, activities);
+    Ok(Schedule::new(projects, graph, capacities, Vec::new()))
+}
+
+#[cfg(test)]
+#[test]
+fn parse_small_instance() {
+    // 1 -> {2, 3} -> 4, successors may continue on the next line
+    let contents = "4 2\n5 3\n0 0 0 2 2\n3\n4 2 1 1 4\n2 3 3 1 4\n0 0 0 0\n";
+    let schedule = parse("small.rcp", contents).unwrap();
+    assert_eq!(schedule.resources, vec![5, 3]);
+    assert_eq!(schedule.projects.len(), 4);
+    assert_eq!(schedule.projects[1].modes(), &[Mode::new(4, vec![2, 1])]);
+    assert_eq!(schedule.projects[3].precedence(), &[2, 3]);
+}
+
+#[test]
+fn truncated_instance_is_rejected() {
+    let error = parse("small.rcp", "4 2\n5 3\n0 0 0 2 2").err().unwrap();
+    assert!(matches!(
+        error,
+        ParseError::UnexpectedEndOfFile { line: 3, .. }
+    ));
+}
@@ -1,201 +1,358 @@
+use crate::reader::{InstanceReader, ParseError};
 use crate::schedule::Schedule;
-use bumpalo::Bump;
 use itertools::izip;
-use shared::id_generator::IdGenerator;
-use shared::project::Project;
-use std::cell::RefCell;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
-
-pub fn read_input<'a>(filename: &'a str, arena: &'a Bump) -> Option<Schedule<'a>> {
-    let file = File::open(filename).unwrap();
-    //unwrap_or_else(|_| panic!("Could not read file {}", filename));
-    let mut lines = BufReader::new(file).lines();
-    // Corresponds to file info at top. We only care about number of jobs
-    skip_lines(&mut lines, 5);
-    let n_activities = get_number_of_activities(lines.next()?.ok()?);
-    //skip_lines(&mut lines, 2);
-    let _horizon = get_horizon(lines.next()?.ok()?);
-    skip_lines(&mut lines, 1);
-    let n_renewable = get_number_of_resources(lines.next()?.ok()?);
-    let n_nonrenewable = get_number_of_resources(lines.next()?.ok()?);
-    skip_lines(&mut lines, 7);
+use shared::project::{Mode, Project};
+use shared::segment_graph::SegmentGraph;
+use std::fs;
 
+/// Reader for the PSPLIB format, used for both the single mode and the multi-mode sets
+pub struct PsplibReader;
+
+impl InstanceReader for PsplibReader {
+    fn read_input(&self, filename: &str) -> Result<Schedule, ParseError> {
+        read_input(filename)
+    }
+}
+
+pub fn read_input(filename: &str) -> Result<Schedule, ParseError> {
+    let contents = fs::read_to_string(filename).map_err(|source| ParseError::Io {
+        file: filename.to_owned(),
+        source,
+    })?;
+    parse(filename, &contents)
+}
+
+/// A PSPLIB file, split into lines so that sections can be looked up by their header
+struct PsplibFile<'f> {
+    file: &'f str,
+    lines: Vec<&'f str>,
+}
+
+impl<'f> PsplibFile<'f> {
+    /// Reads the number after the colon of the line starting with `key`, e.g.
+    /// "jobs (incl. supersource/sink ):  32"
+    fn field(&self, key: &'static str) -> Result<usize, ParseError> {
+        let (index, line) = self
+            .lines
+            .iter()
+            .enumerate()
+            .find(|(_, line)| line.trim_start().starts_with(key))
+            .ok_or_else(|| ParseError::MissingField {
+                file: self.file.to_owned(),
+                line: self.lines.len(),
+                field: key,
+            })?;
+        let token = line
+            .split(':')
+            .nth(1)
+            .and_then(|value| value.split_whitespace().next())
+            .unwrap_or("");
+        parse_number(self.file, index + 1, token)
+    }
+
+    /// The data rows of the section with the given header, together with their line number.
+    /// Rows that do not start with a number, like column headers, are skipped and the section
+    /// ends at the next line of stars.
+    fn section(&self, header: &'static str) -> Result<Vec<(usize, Vec<usize>)>, ParseError> {
+        let start = self
+            .lines
+            .iter()
+            .position(|line| line.trim_start().starts_with(header))
+            .ok_or_else(|| ParseError::MissingSection {
+                file: self.file.to_owned(),
+                line: self.lines.len(),
+                section: header,
+            })?;
+        let mut rows = Vec::new();
+        for (index, line) in self.lines.iter().enumerate().skip(start + 1) {
+            if line.trim_start().starts_with('*') {
+                break;
+            }
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens
+                .first()
+                .is_none_or(|token| token.parse::<usize>().is_err())
+            {
+                continue;
+            }
+            let numbers = tokens
+                .iter()
+                .map(|token| parse_number(self.file, index + 1, token))
+                .collect::<Result<Vec<usize>, ParseError>>()?;
+            rows.push((index + 1, numbers));
+        }
+        Ok(rows)
+    }
+
+    fn malformed(&self, line: usize, message: String) -> ParseError {
+        ParseError::Malformed {
+            file: self.file.to_owned(),
+            line,
+            message,
+        }
+    }
+}
+
+fn parse(filename: &str, contents: &str) -> Result<Schedule, ParseError> {
+    let psplib = PsplibFile {
+        file: filename,
+        lines: contents.lines().collect(),
+    };
+    let n_activities = psplib.field("jobs")?;
+    let n_renewable = psplib.field("- renewable")?;
+    let n_nonrenewable = psplib.field("- nonrenewable")?;
+    let n_resources = n_renewable + n_nonrenewable;
+
+    // jobnr. #modes #successors successors
+    let precedence = psplib.section("PRECEDENCE RELATIONS")?;
+    if precedence.len() != n_activities {
+        return Err(psplib.malformed(
+            precedence
+                .last()
+                .map_or(psplib.lines.len(), |(line, _)| *line),
+            format!("expected {} jobs, found {}", n_activities, precedence.len()),
+        ));
+    }
     let activities: Vec<usize> = (1..n_activities + 1).collect();
-    let mut modes: Vec<usize> = Vec::new();
-    let mut modes_per_activity: Vec<Vec<usize>> = vec![vec![1]; n_activities];
-    let mut successors: Vec<Vec<usize>> = vec![Default::default(); n_activities];
-
-    for i in 0..n_activities {
-        let line = line_to_numbers(lines.next()?.ok()?);
-        let mut n_modes = *line.get(1).unwrap();
-        let n_modes = 1;
-        let modes_i = modes_per_activity.get_mut(i).unwrap();
-        for _ in 0..n_modes {
-            modes_i.push(modes.len());
-            modes.push(modes.len());
+    let mut modes_per_activity: Vec<usize> = Vec::with_capacity(n_activities);
+    let mut successors: Vec<Vec<usize>> = Vec::with_capacity(n_activities);
+    for (job, (line, row)) in izip!(&activities, &precedence) {
+        if row.len() < 3 || row[0] != *job || row[1] == 0 || row.len() != 3 + row[2] {
+            return Err(psplib.malformed(
+                *line,
+                format!("invalid precedence relation for job {}", job),
+            ));
         }
-        if line.len() > 3 {
-            let successors_i = successors.get_mut(i).unwrap();
-            for j in 3..line.len() {
-                successors_i.push(*line.get(j).unwrap() as usize);
+        if let Some(successor) = row[3..].iter().find(|s| **s == 0 || **s > n_activities) {
+            return Err(ParseError::UnknownSuccessor {
+                file: filename.to_owned(),
+                line: *line,
+                successor: *successor,
+            });
+        }
+        modes_per_activity.push(row[1]);
+        successors.push(row[3..].to_vec());
+    }
+
+    // jobnr. mode duration resources, where only the first mode of a job starts with the job
+    let requests = psplib.section("REQUESTS/DURATIONS")?;
+    let mut requests = requests.iter();
+    let mut modes_per_project: Vec<Vec<Mode>> = Vec::with_capacity(n_activities);
+    for (job, n_modes) in izip!(&activities, &modes_per_activity) {
+        let mut modes: Vec<Mode> = Vec::with_capacity(*n_modes);
+        for mode in 1..n_modes + 1 {
+            let (line, row) =
+                requests
+                    .next()
+                    .ok_or_else(|| ParseError::UnexpectedEndOfSection {
+                        file: filename.to_owned(),
+                        line: psplib.lines.len(),
+                        section: "REQUESTS/DURATIONS",
+                    })?;
+            let row = if mode == 1 && row.len() == 3 + n_resources && row[0] == *job {
+                &row[1..]
+            } else {
+                &row[..]
+            };
+            if row.len() != 2 + n_resources || row[0] != mode {
+                return Err(
+                    psplib.malformed(*line, format!("invalid mode {} of job {}", mode, job))
+                );
             }
+            modes.push(Mode::new(row[1], row[2..].to_vec()));
         }
+        modes_per_project.push(modes);
     }
 
-    let mut durations_per_mode: Vec<usize> = Vec::with_capacity(modes.len());
-    let mut resources_per_mode: Vec<Vec<usize>> =
-        vec![vec![Default::default(); n_renewable + n_nonrenewable]; modes.len()];
-
-    skip_lines(&mut lines, 4);
-    for mode in 0..modes.len() {
-        let line = line_to_numbers(lines.next()?.ok()?);
-        durations_per_mode.push(
-            *line
-                .get(line.len() - (n_renewable + n_nonrenewable + 1))
-                .unwrap() as usize,
-        );
-        let resources_mode = resources_per_mode.get_mut(mode).unwrap();
-        (0..n_renewable).for_each(|i| {
-            resources_mode[i] = *line
-                .get(line.len() + i - n_nonrenewable - n_renewable)
-                .unwrap() as usize;
-        });
-        (n_renewable..(n_renewable + n_nonrenewable)).for_each(|i| {
-            resources_mode[i] = *line
-                .get(line.len() + i - n_nonrenewable - n_renewable)
-                .unwrap() as usize;
-        });
+    let availabilities = psplib.section("RESOURCEAVAILABILITIES")?;
+    let (line, capacities) =
+        availabilities
+            .first()
+            .ok_or_else(|| ParseError::UnexpectedEndOfSection {
+                file: filename.to_owned(),
+                line: psplib.lines.len(),
+                section: "RESOURCEAVAILABILITIES",
+            })?;
+    if capacities.len() != n_resources {
+        return Err(psplib.malformed(
+            *line,
+            format!(
+                "expected {} capacities, found {}",
+                n_resources,
+                capacities.len()
+            ),
+        ));
     }
 
-    skip_lines(&mut lines, 3);
-    let line = line_to_numbers(lines.next()?.ok()?);
-    let capacity_per_renewable_resource: Vec<usize> = line
-        .get(..n_renewable)
-        .unwrap()
-        .iter()
-        .map(|x| *x as usize)
-        .collect();
-    let capacity_per_nonrenewable_resource = line.get(n_renewable..).unwrap().to_vec();
-    let schedule = Schedule::new(
-        create_projects(
-            arena,
-            resources_per_mode,
-            successors,
-            activities,
-            durations_per_mode,
-        ),
-        capacity_per_renewable_resource,
-    );
-    Some(schedule)
+    let (projects, graph) = create_projects(modes_per_project, successors, activities);
+    Ok(Schedule::new(
+        projects,
+        graph,
+        capacities[..n_renewable].to_vec(),
+        capacities[n_renewable..].to_vec(),
+    ))
+}
+
+fn parse_number(file: &str, line: usize, token: &str) -> Result<usize, ParseError> {
+    token.parse().map_err(|_| ParseError::InvalidNumber {
+        file: file.to_owned(),
+        line,
+        token: token.to_owned(),
+    })
 }
-fn create_projects(
-    arena: &Bump,
-    resources: Vec<Vec<usize>>,
+
+pub(crate) fn create_projects(
+    modes: Vec<Vec<Mode>>,
     successors: Vec<Vec<usize>>,
     projs: Vec<usize>,
-    durations: Vec<usize>,
-) -> Vec<&Project<'_>> {
-    let mut projects: Vec<&Project> = Vec::new();
-    let mut id_gen = IdGenerator::generator_for_segment();
-    for (resource, proj, duration) in izip!(resources, projs, durations) {
-        let project = arena.alloc(Project::new(
-            duration,
-            proj,
-            resource,
-            RefCell::new(Vec::new()),
-            &mut id_gen,
-        ));
-        projects.push(project);
+) -> (Vec<Project>, SegmentGraph) {
+    let mut graph = SegmentGraph::new();
+    let mut projects: Vec<Project> = Vec::new();
+    for (modes, proj) in izip!(modes, projs) {
+        projects.push(Project::with_modes(modes, proj, &mut graph));
     }
     // Ids start at 1 and vec starts at 0, so there is an ofset by one going on here
     // Projects are sorted by ids
     projects.sort();
-    connect_precedence(&projects, successors);
-    link_with_precedence(&projects);
+    connect_precedence(&mut projects, successors);
+    link_with_precedence(&projects, &mut graph);
 
-    projects
+    (projects, graph)
 }
-fn connect_precedence<'a>(projects: &Vec<&'a Project<'a>>, successors: Vec<Vec<usize>>) {
+fn connect_precedence(projects: &mut [Project], successors: Vec<Vec<usize>>) {
     for (index, successors) in successors.into_iter().enumerate() {
-        let precedent = projects.get(index).unwrap();
+        let precedent = projects[index].id();
         for successor in successors {
-            //println!("Suc {} of {}", index + 1, successor);
             // successor is based on the id's, not the index
-            let suc = projects.get(successor - 1).unwrap();
-            suc.add_presedence(precedent);
+            projects[successor - 1].add_presedence(precedent);
         }
     }
 }
-fn link_with_precedence<'a>(projects: &Vec<&'a Project<'a>>) {
+fn link_with_precedence(projects: &[Project], graph: &mut SegmentGraph) {
     for project in projects {
-        project.link_with_precedents();
-    }
-}
-fn skip_lines(lines: &mut Lines<BufReader<File>>, n: u32) {
-    for _ in 0..n {
-        lines.next();
+        for precedent in project.precedence() {
+            project.link_with_precedent(&projects[precedent - 1], graph);
+        }
     }
 }
-
-fn get_number_of_activities(line: String) -> usize {
-    line.split(':').nth(1).unwrap().trim().parse().unwrap()
-}
-
-fn get_number_of_resources(line: String) -> usize {
-    line.split(':')
-        .nth(1)
-        .unwrap()
-        .split_whitespace()
-        .next()
-        .unwrap()
-        .parse()
-        .unwrap()
-}
-
-fn line_to_numbers(line: String) -> Vec<u32> {
-    line.split_whitespace()
-        .map(|s| s.parse())
-        .filter(|s| s.is_ok())
-        .map(|s| s.unwrap())
-        .collect()
-}
-
-fn get_horizon(line: String) -> usize {
-    line.split(':').nth(1).unwrap().trim().parse().unwrap()
-}
 #[cfg(test)]
 #[test]
 fn proj_creation_from_read() {
     //Project of size 1, with no dependencies, and resource 1
-    let rsr = vec![vec![1]];
+    let modes = vec![vec![Mode::new(1, vec![1])]];
     let act = vec![1];
     let dep: Vec<Vec<usize>> = vec![vec![]];
-    let arena = Bump::new();
 
-    let created_project = create_projects(&arena, rsr, dep, act, vec![1])[0];
-    let expected_project =
-        Project::new(1, 1, vec![1], RefCell::new(Vec::new()), &mut IdGenerator(0));
+    let (created_projects, graph) = create_projects(modes, dep, act);
+    let expected_project = Project::new(1, 1, vec![1], &mut SegmentGraph::new());
     //simpl test
-    assert_eq!(*created_project, expected_project);
+    assert_eq!(created_projects[0], expected_project);
+    assert_eq!(graph.len(), 1);
 }
 #[test]
 fn proj_creation_three() {
     // 1 -> 2 -> 3 (3 depends on 2, which depends on 1)
     // all have same resource usage, which is vec1
-    let resources = vec![vec![1], vec![1], vec![1]];
-    let mut id_gen = IdGenerator(0);
-    let project1 = Project::new(1, 1, vec![1], RefCell::new(Vec::new()), &mut id_gen);
-    let project2 = Project::new(1, 2, vec![1], RefCell::new(vec![&project1]), &mut id_gen);
-    let project3 = Project::new(1, 3, vec![1], RefCell::new(vec![&project2]), &mut id_gen);
-    let expected_projects = vec![&project1, &project2, &project3];
-
-    let arena = Bump::new();
+    let modes = vec![
+        vec![Mode::new(1, vec![1])],
+        vec![Mode::new(1, vec![1])],
+        vec![Mode::new(1, vec![1])],
+    ];
+    let mut graph = SegmentGraph::new();
+    let project1 = Project::new(1, 1, vec![1], &mut graph);
+    let project2 = Project::new(1, 2, vec![1], &mut graph);
+    let project3 = Project::new(1, 3, vec![1], &mut graph);
+    let expected_projects = [project1, project2, project3];
+
     let successors = vec![vec![2], vec![3], vec![]];
     let projs = vec![1, 2, 3];
-    let durations = vec![1, 1, 1];
-    let generetad_projects = create_projects(&arena, resources, successors, projs, durations);
-    for generated in generetad_projects {
-        assert!(expected_projects.contains(&generated));
+    let (generetad_projects, graph) = create_projects(modes, successors, projs);
+    for generated in generetad_projects.iter() {
+        assert!(expected_projects.contains(generated));
     }
+    assert_eq!(generetad_projects[1].precedence(), &[1]);
+    assert_eq!(generetad_projects[2].precedence(), &[2]);
+    // Every project has a single segment, linked in a chain
+    assert_eq!(graph.predecessors(2), &[1]);
+    assert_eq!(graph.predecessors(1), &[0]);
+}
+
+#[cfg(test)]
+const MULTI_MODE: &str = "************************************************************************
+file with basedata            : small.bas
+initial value random generator: 1
+************************************************************************
+projects                      :  1
+jobs (incl. supersource/sink ):  4
+horizon                       :  20
+RESOURCES
+  - renewable                 :  1   R
+  - nonrenewable              :  1   N
+  - doubly constrained        :  0   D
+************************************************************************
+PROJECT INFORMATION:
+pronr.  #jobs rel.date duedate tardcost  MPM-Time
+    1      2      0       10        5       10
+************************************************************************
+PRECEDENCE RELATIONS:
+jobnr.    #modes  #successors   successors
+   1        1          2           2   3
+   2        2          1           4
+   3        1          1           4
+   4        1          0
+************************************************************************
+REQUESTS/DURATIONS:
+jobnr. mode duration  R 1  N 1
+------------------------------------------------------------------------
+  1      1     0       0    0
+  2      1     2       2    3
+         2     3       1    1
+  3      1     1       2    0
+  4      1     0       0    0
+************************************************************************
+RESOURCEAVAILABILITIES:
+  R 1  N 1
+    3    4
+************************************************************************
+";
+
+#[test]
+fn parse_multi_mode() {
+    let schedule = parse("small.mm", MULTI_MODE).unwrap();
+    assert_eq!(schedule.resources, vec![3]);
+    assert_eq!(schedule.nonrenewable, vec![4]);
+    assert_eq!(
+        schedule.projects[1].modes(),
+        &[Mode::new(2, vec![2, 3]), Mode::new(3, vec![1, 1])]
+    );
+    assert_eq!(schedule.projects[3].precedence(), &[2, 3]);
+}
+
+#[test]
+fn parse_errors_point_at_the_line() {
+    let invalid = MULTI_MODE.replace(
+        "  3      1     1       2    0",
+        "  3      1     x       2    0",
+    );
+    let error = parse("small.mm", &invalid).err().unwrap();
+    assert!(matches!(error, ParseError::InvalidNumber { line: 30, .. }));
+
+    let unknown = MULTI_MODE.replace(
+        "   3        1          1           4",
+        "   3        1          1           5",
+    );
+    let error = parse("small.mm", &unknown).err().unwrap();
+    assert!(matches!(
+        error,
+        ParseError::UnknownSuccessor {
+            line: 21,
+            successor: 5,
+            ..
+        }
+    ));
+
+    let missing = MULTI_MODE.replace("RESOURCEAVAILABILITIES", "AVAILABILITIES");
+    let error = parse("small.mm", &missing).err().unwrap();
+    assert!(matches!(error, ParseError::MissingSection { .. }));
+    assert!(error.to_string().starts_with("small.mm:"));
 }
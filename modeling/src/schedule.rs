@@ -1,19 +1,30 @@
-use shared::project::Project;
+use shared::{project::Project, segment_graph::SegmentGraph};
 
-pub struct Schedule<'a> {
-    pub(crate) projects: Vec<&'a Project<'a>>,
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    pub(crate) projects: Vec<Project>,
+    pub(crate) graph: SegmentGraph,
     pub(crate) resources: Vec<usize>,
+    // Budget of each nonrenewable resource over the whole schedule
+    pub(crate) nonrenewable: Vec<usize>,
 }
 
-impl<'a> Schedule<'a> {
-    pub fn new(projects: Vec<&'a Project<'a>>, resources: Vec<usize>) -> Self {
+impl Schedule {
+    pub fn new(
+        projects: Vec<Project>,
+        graph: SegmentGraph,
+        resources: Vec<usize>,
+        nonrenewable: Vec<usize>,
+    ) -> Self {
         Self {
             projects,
+            graph,
             resources,
+            nonrenewable,
         }
     }
 
-    pub fn projects(&'a self) -> &[&Project] {
+    pub fn projects(&self) -> &[Project] {
         self.projects.as_ref()
     }
 }
@@ -0,0 +1,424 @@
+use crate::{
+    decoder::{DecodedProject, DecodedSchedule},
+    schedule::Schedule,
+};
+use std::{collections::HashMap, fmt::Display};
+
+/// Something a decoded schedule does that the instance does not allow. Activities are the
+/// projects of the instance, and all times are those of the decoded schedule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// The instance has an activity that is not in the schedule
+    MissingActivity {
+        activity: usize,
+    },
+    /// The schedule has an activity that is not in the instance
+    UnknownActivity {
+        activity: usize,
+    },
+    InvalidMode {
+        activity: usize,
+        mode: usize,
+    },
+    /// The activity starts before one of its predecessors is done
+    Precedence {
+        activity: usize,
+        start: usize,
+        predecessor: usize,
+        predecessor_end: usize,
+    },
+    /// More of a renewable resource is used at a time than is available
+    Capacity {
+        time: usize,
+        resource: usize,
+        usage: usize,
+        capacity: usize,
+        activities: Vec<usize>,
+    },
+    /// The selected modes use more of a nonrenewable resource than the budget
+    Budget {
+        resource: usize,
+        usage: usize,
+        budget: usize,
+    },
+    /// No piece of the activity processes the jiffy
+    Uncovered {
+        activity: usize,
+        jiffy: usize,
+    },
+    /// More than one piece of the activity processes the jiffy
+    Duplicated {
+        activity: usize,
+        jiffy: usize,
+    },
+    /// The piece does not take its length plus the set-up cost of a resumed piece
+    SetUp {
+        activity: usize,
+        segment: usize,
+        start: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// The piece starts before the piece processing the jiffies before it is done
+    EarlyResume {
+        activity: usize,
+        segment: usize,
+        start: usize,
+        previous_end: usize,
+    },
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::MissingActivity { activity } => {
+                write!(f, "activity {activity}: not in the schedule")
+            }
+            Violation::UnknownActivity { activity } => {
+                write!(f, "activity {activity}: not in the instance")
+            }
+            Violation::InvalidMode { activity, mode } => {
+                write!(f, "activity {activity}: mode {mode} does not exist")
+            }
+            Violation::Precedence {
+                activity,
+                start,
+                predecessor,
+                predecessor_end,
+            } => write!(
+                f,
+                "activity {activity}, time {start}: starts before predecessor {predecessor} is done at {predecessor_end}"
+            ),
+            Violation::Capacity {
+                time,
+                resource,
+                usage,
+                capacity,
+                activities,
+            } => write!(
+                f,
+                "time {time}, resource {resource}: {usage} used by activities {activities:?}, but the capacity is {capacity}"
+            ),
+            Violation::Budget {
+                resource,
+                usage,
+                budget,
+            } => write!(
+                f,
+                "nonrenewable resource {resource}: {usage} used, but the budget is {budget}"
+            ),
+            Violation::Uncovered { activity, jiffy } => {
+                write!(f, "activity {activity}: jiffy {jiffy} is never processed")
+            }
+            Violation::Duplicated { activity, jiffy } => {
+                write!(f, "activity {activity}: jiffy {jiffy} is processed more than once")
+            }
+            Violation::SetUp {
+                activity,
+                segment,
+                start,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "activity {activity}, time {start}: segment {segment} takes {actual} instead of {expected}"
+            ),
+            Violation::EarlyResume {
+                activity,
+                segment,
+                start,
+                previous_end,
+            } => write!(
+                f,
+                "activity {activity}, time {start}: segment {segment} resumes before the previous piece is done at {previous_end}"
+            ),
+        }
+    }
+}
+
+/// Checks a decoded schedule against the instance it was generated from. This does not look at
+/// the encoding at all, so it also catches solutions that only satisfy a wrong encoding.
+pub fn validate(instance: &Schedule, schedule: &DecodedSchedule) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let decoded: HashMap<usize, &DecodedProject> = schedule
+        .projects
+        .iter()
+        .map(|project| (project.id, project))
+        .collect();
+    for project in schedule.projects.iter() {
+        if !instance.projects().iter().any(|p| p.id() == project.id) {
+            violations.push(Violation::UnknownActivity {
+                activity: project.id,
+            });
+        }
+    }
+
+    // The activities that can be checked, with the mode they are executed in
+    let mut executed = Vec::new();
+    for project in instance.projects() {
+        let Some(decoded_project) = decoded.get(&project.id()) else {
+            violations.push(Violation::MissingActivity {
+                activity: project.id(),
+            });
+            continue;
+        };
+        match project.modes().get(decoded_project.mode) {
+            Some(mode) => executed.push((project, *decoded_project, mode)),
+            None => violations.push(Violation::InvalidMode {
+                activity: project.id(),
+                mode: decoded_project.mode,
+            }),
+        }
+    }
+
+    for (project, decoded_project, mode) in executed.iter() {
+        check_pieces(
+            decoded_project,
+            mode.duration,
+            schedule.set_up_cost,
+            &mut violations,
+        );
+        let Some(start) = decoded_project.start else {
+            continue;
+        };
+        for predecessor in project.precedence() {
+            if let Some(predecessor_end) = decoded.get(predecessor).and_then(|p| p.end) {
+                if predecessor_end > start {
+                    violations.push(Violation::Precedence {
+                        activity: project.id(),
+                        start,
+                        predecessor: *predecessor,
+                        predecessor_end,
+                    });
+                }
+            }
+        }
+    }
+
+    // Renewable resources are used while a piece runs, set-up included
+    for (resource, capacity) in instance.resources.iter().enumerate() {
+        for time in 0..schedule.makespan {
+            let running: Vec<(usize, usize)> = executed
+                .iter()
+                .filter(|(_, decoded_project, _)| {
+                    decoded_project
+                        .segments
+                        .iter()
+                        .any(|segment| segment.start <= time && time < segment.end)
+                })
+                .map(|(project, _, mode)| (project.id(), mode.resource[resource]))
+                .filter(|(_, usage)| *usage > 0)
+                .collect();
+            let usage = running.iter().map(|(_, usage)| usage).sum();
+            if usage > *capacity {
+                violations.push(Violation::Capacity {
+                    time,
+                    resource,
+                    usage,
+                    capacity: *capacity,
+                    activities: running.into_iter().map(|(activity, _)| activity).collect(),
+                });
+            }
+        }
+    }
+    for (index, budget) in instance.nonrenewable.iter().enumerate() {
+        let resource = instance.resources.len() + index;
+        let usage = executed
+            .iter()
+            .map(|(_, _, mode)| mode.resource[resource])
+            .sum();
+        if usage > *budget {
+            violations.push(Violation::Budget {
+                resource: index,
+                usage,
+                budget: *budget,
+            });
+        }
+    }
+    violations
+}
+
+/// Every jiffy of the activity is processed by exactly one piece, one piece after the other,
+/// with the set-up cost paid for every piece that resumes the activity
+fn check_pieces(
+    project: &DecodedProject,
+    duration: usize,
+    set_up_cost: usize,
+    violations: &mut Vec<Violation>,
+) {
+    for jiffy in 1..duration + 1 {
+        let pieces = project
+            .segments
+            .iter()
+            .filter(|segment| segment.start_jiff <= jiffy && jiffy <= segment.end_jiff)
+            .count();
+        if pieces == 0 {
+            violations.push(Violation::Uncovered {
+                activity: project.id,
+                jiffy,
+            });
+        } else if pieces > 1 {
+            violations.push(Violation::Duplicated {
+                activity: project.id,
+                jiffy,
+            });
+        }
+    }
+    if duration == 0 {
+        return;
+    }
+    let mut pieces: Vec<_> = project.segments.iter().collect();
+    pieces.sort_by_key(|segment| (segment.start_jiff, segment.start));
+    for segment in pieces.iter() {
+        let length = segment.end_jiff + 1 - segment.start_jiff;
+        let expected = if segment.start_jiff > 1 {
+            length + set_up_cost
+        } else {
+            length
+        };
+        if segment.end - segment.start != expected {
+            violations.push(Violation::SetUp {
+                activity: project.id,
+                segment: segment.id,
+                start: segment.start,
+                expected,
+                actual: segment.end - segment.start,
+            });
+        }
+    }
+    for pair in pieces.windows(2) {
+        if pair[1].start < pair[0].end {
+            violations.push(Violation::EarlyResume {
+                activity: project.id,
+                segment: pair[1].id,
+                start: pair[1].start,
+                previous_end: pair[0].end,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate, Violation};
+    use crate::{
+        decoder::{DecodedProject, DecodedSchedule, DecodedSegment},
+        readerSM::create_projects,
+        schedule::Schedule,
+    };
+    use shared::project::Mode;
+
+    // 1 -> 2 -> 4 and 1 -> 3 -> 4, where 2 and 3 both need 2 of the 3 available
+    fn instance() -> Schedule {
+        let modes = vec![
+            vec![Mode::new(0, vec![0])],
+            vec![Mode::new(2, vec![2])],
+            vec![Mode::new(1, vec![2])],
+            vec![Mode::new(0, vec![0])],
+        ];
+        let successors = vec![vec![2, 3], vec![4], vec![4], vec![]];
+        let (projects, graph) = create_projects(modes, successors, vec![1, 2, 3, 4]);
+        Schedule::new(projects, graph, vec![3], Vec::new())
+    }
+
+    fn project(id: usize, segments: &[(usize, usize, usize, usize)]) -> DecodedProject {
+        let segments: Vec<DecodedSegment> = segments
+            .iter()
+            .enumerate()
+            .map(
+                |(index, (start_jiff, end_jiff, start, end))| DecodedSegment {
+                    id: id * 10 + index,
+                    start_jiff: *start_jiff,
+                    end_jiff: *end_jiff,
+                    start: *start,
+                    end: *end,
+                },
+            )
+            .collect();
+        DecodedProject {
+            id,
+            mode: 0,
+            start: segments.iter().map(|s| s.start).min(),
+            end: segments.iter().map(|s| s.end).max(),
+            segments,
+            preemptions: Vec::new(),
+        }
+    }
+
+    fn schedule(projects: Vec<DecodedProject>) -> DecodedSchedule {
+        DecodedSchedule {
+            makespan: projects.iter().filter_map(|p| p.end).max().unwrap_or(0),
+            objective: None,
+            set_up_cost: 1,
+            projects,
+        }
+    }
+
+    #[test]
+    fn valid_preemptive_schedule() {
+        // 2 is preempted after its first jiffy to let 3 run, and pays 1 to resume
+        let decoded = schedule(vec![
+            project(1, &[(0, 0, 0, 0)]),
+            project(2, &[(1, 1, 0, 1), (2, 2, 2, 4)]),
+            project(3, &[(1, 1, 1, 2)]),
+            project(4, &[(0, 0, 4, 4)]),
+        ]);
+        assert_eq!(validate(&instance(), &decoded), Vec::new());
+    }
+
+    #[test]
+    fn report_every_violation() {
+        let decoded = schedule(vec![
+            project(1, &[(0, 0, 0, 0)]),
+            // Overlaps with 3, and resumes without paying for the set-up
+            project(2, &[(1, 1, 0, 1), (2, 2, 1, 2)]),
+            project(3, &[(1, 1, 0, 1)]),
+            // Starts before 2 is done
+            project(4, &[(0, 0, 1, 1)]),
+        ]);
+        assert_eq!(
+            validate(&instance(), &decoded),
+            vec![
+                Violation::SetUp {
+                    activity: 2,
+                    segment: 21,
+                    start: 1,
+                    expected: 2,
+                    actual: 1,
+                },
+                Violation::Precedence {
+                    activity: 4,
+                    start: 1,
+                    predecessor: 2,
+                    predecessor_end: 2,
+                },
+                Violation::Capacity {
+                    time: 0,
+                    resource: 0,
+                    usage: 4,
+                    capacity: 3,
+                    activities: vec![2, 3],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn report_coverage() {
+        let decoded = schedule(vec![
+            project(1, &[(0, 0, 0, 0)]),
+            project(2, &[(1, 2, 0, 2), (2, 2, 2, 4)]),
+            project(3, &[]),
+            project(4, &[(0, 0, 4, 4)]),
+        ]);
+        let violations = validate(&instance(), &decoded);
+        assert!(violations.contains(&Violation::Duplicated {
+            activity: 2,
+            jiffy: 2
+        }));
+        assert!(violations.contains(&Violation::Uncovered {
+            activity: 3,
+            jiffy: 1
+        }));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::schedule::Schedule;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// What the variables of a generated file stand for. It is written next to the file, so a
+/// solution can be decoded without reading the instance again.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VariableMap {
+    /// The number of variables in the generated file, including the ones of the encodings
+    pub variables: usize,
+    pub set_up_cost: usize,
+    pub projects: Vec<ProjectVariables>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectVariables {
+    pub id: usize,
+    /// One variable per mode, empty for a project with a single mode
+    pub mode_variables: Vec<usize>,
+    pub segments: Vec<SegmentVariables>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SegmentVariables {
+    pub id: usize,
+    pub mode: usize,
+    /// The first and last jiffy of the project that the segment covers
+    pub start_jiff: usize,
+    pub end_jiff: usize,
+    /// The duration including the set-up cost
+    pub duration: usize,
+    /// The SATSVars, true if the segment starts at the time
+    pub start_variables: Vec<TimedVariable>,
+    /// The SATUVars, true if the segment is running at the time
+    pub active_variables: Vec<TimedVariable>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimedVariable {
+    pub variable: usize,
+    pub time: usize,
+}
+
+impl VariableMap {
+    /// Should be called once all variables of the schedule have been generated
+    pub fn new(schedule: &Schedule, variables: usize, set_up_cost: usize) -> Self {
+        let projects = schedule
+            .projects
+            .iter()
+            .map(|project| ProjectVariables {
+                id: project.id(),
+                mode_variables: project.mode_variables().to_vec(),
+                segments: project
+                    .segments()
+                    .iter()
+                    .map(|id| schedule.graph.segment(*id))
+                    .map(|segment| SegmentVariables {
+                        id: segment.id(),
+                        mode: segment.mode,
+                        start_jiff: segment.start_jiff,
+                        end_jiff: segment.end_jiff(),
+                        duration: segment.duration(),
+                        start_variables: segment
+                            .variables
+                            .iter()
+                            .map(|s_var| TimedVariable {
+                                variable: s_var.id(),
+                                time: s_var.time(),
+                            })
+                            .collect(),
+                        active_variables: segment
+                            .uvariables
+                            .iter()
+                            .map(|u_var| TimedVariable {
+                                variable: u_var.id(),
+                                time: u_var.time_at(),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            })
+            .collect();
+        Self {
+            variables,
+            set_up_cost,
+            projects,
+        }
+    }
+
+    /// The map of '2Fj301_1.wcnf' is '2Fj301_1.map.json'
+    pub fn path_for(wcnf: &Path) -> PathBuf {
+        wcnf.with_extension("map.json")
+    }
+}
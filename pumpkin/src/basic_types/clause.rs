@@ -6,6 +6,8 @@ pub struct Clause {
     is_learned: bool,
     is_deleted: bool,
     is_protected_aganst_deletion: bool,
+    is_used: bool,
+    is_vivified: bool,
     lbd: u32,
     activity: f32,
 }
@@ -20,6 +22,8 @@ impl Clause {
             is_learned,
             is_deleted: false,
             is_protected_aganst_deletion: false,
+            is_used: false,
+            is_vivified: false,
             lbd: num_literals, //pessimistic lbd
             activity: 0.0,
         }
@@ -37,6 +41,11 @@ impl Clause {
         self.is_protected_aganst_deletion
     }
 
+    //a clause is used when it takes part in conflict analysis, the flag is cleared when the learned clauses are reduced
+    pub fn is_used(&self) -> bool {
+        self.is_used
+    }
+
     pub fn is_deleted(&self) -> bool {
         self.is_deleted
     }
@@ -71,6 +80,22 @@ impl Clause {
         self.is_protected_aganst_deletion = false;
     }
 
+    pub fn mark_used(&mut self) {
+        self.is_used = true;
+    }
+
+    pub fn clear_used(&mut self) {
+        self.is_used = false;
+    }
+
+    pub fn is_vivified(&self) -> bool {
+        self.is_vivified
+    }
+
+    pub fn mark_vivified(&mut self) {
+        self.is_vivified = true;
+    }
+
     pub fn update_lbd(&mut self, new_lbd: u32) {
         pumpkin_assert_moderate!(new_lbd < self.lbd);
         self.lbd = new_lbd;
@@ -1,12 +1,13 @@
 use std::fs;
 
-use crate::basic_types::PropositionalVariable;
+use crate::{basic_types::PropositionalVariable, parsers::dimacs::parse_capacity_constraint_line};
 
-use super::{FileFormat, Literal, Solution};
+use super::{FileFormat, Literal, Solution, WeightedLiteral};
 
 pub struct Instance {
     pub hard_clauses: Vec<Vec<Literal>>,
     pub soft_clauses: Vec<SoftClause>,
+    pub capacity_constraints: Vec<CapacityConstraint>,
 }
 
 impl Instance {
@@ -14,6 +15,7 @@ impl Instance {
         Instance {
             hard_clauses: vec![],
             soft_clauses: vec![],
+            capacity_constraints: vec![],
         }
     }
 
@@ -23,6 +25,18 @@ impl Instance {
             .any(|clause| Instance::is_clause_violated(clause, solution))
     }
 
+    pub fn are_capacity_constraints_violated(&self, solution: &Solution) -> bool {
+        self.capacity_constraints.iter().any(|capacity_constraint| {
+            capacity_constraint
+                .terms
+                .iter()
+                .filter(|term| solution.get_literal_value(term.literal))
+                .map(|term| term.weight)
+                .sum::<u64>()
+                > capacity_constraint.capacity
+        })
+    }
+
     pub fn compute_soft_clause_violation(&self, solution: &Solution) -> u64 {
         self.soft_clauses
             .iter()
@@ -37,7 +51,9 @@ impl Instance {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.hard_clauses.is_empty() && self.soft_clauses.is_empty()
+        self.hard_clauses.is_empty()
+            && self.soft_clauses.is_empty()
+            && self.capacity_constraints.is_empty()
     }
 
     fn is_clause_violated(literals: &[Literal], solution: &Solution) -> bool {
@@ -136,6 +152,26 @@ impl Instance {
         let mut num_clauses_read = 0;
         //read clauses one by one
         for line in lines {
+            //capacity constraints are not counted as clauses
+            if line.starts_with('r') {
+                let capacity_constraint = parse_capacity_constraint_line(line);
+                self.capacity_constraints.push(CapacityConstraint {
+                    terms: capacity_constraint
+                        .terms
+                        .iter()
+                        .map(|(weight, literal)| WeightedLiteral {
+                            literal: Literal::new(
+                                PropositionalVariable::new(literal.unsigned_abs() as u32),
+                                *literal > 0,
+                            ),
+                            weight: *weight,
+                        })
+                        .collect(),
+                    capacity: capacity_constraint.capacity,
+                });
+                continue;
+            }
+
             let mut raw_integers = line
                 .split_whitespace()
                 .filter_map(|s| {
@@ -210,3 +246,8 @@ pub struct SoftClause {
     pub literals: Vec<Literal>,
     pub weight: u64,
 }
+
+pub struct CapacityConstraint {
+    pub terms: Vec<WeightedLiteral>,
+    pub capacity: u64,
+}
@@ -20,7 +20,6 @@ mod propositional_variable;
 mod pumpkin_execution_flag;
 mod solution;
 mod solution_tracker;
-mod solution_value_pair;
 mod stopwatch;
 mod weighted_literal;
 
@@ -48,6 +47,5 @@ pub use propositional_variable::PropositionalVariableGeneratorIterator;
 pub use pumpkin_execution_flag::PumpkinExecutionFlag;
 pub use solution::Solution;
 pub use solution_tracker::SolutionTracker;
-pub use solution_value_pair::SolutionValuePair;
 pub use stopwatch::Stopwatch;
 pub use weighted_literal::WeightedLiteral;
@@ -8,6 +8,7 @@ pub enum PumpkinExecutionFlag {
     Feasible {
         feasible_solution: Solution,
         objective_value: u64,
+        lower_bound: u64,
     },
     Infeasible,
     Timeout,
@@ -5,6 +5,7 @@ use crate::{
 
 use super::{IntegerVariable, Literal, PropositionalVariable};
 
+#[derive(Clone)]
 pub struct Solution {
     truth_values: Vec<bool>,
     integer_values: Vec<i32>,
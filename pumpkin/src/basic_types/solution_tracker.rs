@@ -1,37 +1,127 @@
+use log::info;
+use std::time::{Duration, Instant};
+
+use crate::pumpkin_asserts::pumpkin_assert_simple;
+
 use super::{Function, Solution};
 
+//keeps track of the best solution and the proven lower bound during optimisation
+//  every improvement is reported as soon as it is found, so the output is consistent regardless of how the search ends
 pub struct SolutionTracker {
     objective_function: Function,
+    best_solution: Option<Solution>,
+    best_objective_value: u64,
+    lower_bound: u64,
+    is_optimal: bool,
+    improvements: Vec<Improvement>,
+    time_start: Instant,
+}
+
+//an improving solution, with the time since the tracker was created
+#[derive(Clone, Copy, Debug)]
+pub struct Improvement {
+    pub objective_value: u64,
+    pub time: Duration,
 }
 
 impl SolutionTracker {
     pub fn new(objective_function: &Function) -> SolutionTracker {
         SolutionTracker {
             objective_function: objective_function.clone(),
+            best_solution: None,
+            best_objective_value: u64::MAX,
+            lower_bound: objective_function.get_constant_term(),
+            is_optimal: false,
+            improvements: vec![],
+            time_start: Instant::now(),
         }
     }
 
+    pub fn has_solution(&self) -> bool {
+        self.best_solution.is_some()
+    }
+
     pub fn get_best_objective_value(&self) -> u64 {
-        todo!();
+        pumpkin_assert_simple!(self.has_solution(), "No solution has been found yet.");
+        self.best_objective_value
+    }
+
+    pub fn get_best_solution(&self) -> Option<&Solution> {
+        self.best_solution.as_ref()
+    }
+
+    pub fn get_lower_bound(&self) -> u64 {
+        self.lower_bound
     }
 
-    pub fn get_best_solution(&self) -> u64 {
-        todo!();
+    //the difference between the best solution and the lower bound, or None if there is no solution yet
+    pub fn get_gap(&self) -> Option<u64> {
+        self.best_solution
+            .as_ref()
+            .map(|_| self.best_objective_value - self.lower_bound)
+    }
+
+    pub fn get_improvements(&self) -> &[Improvement] {
+        &self.improvements
     }
 
     pub fn has_optimal_solution(&self) -> bool {
-        todo!()
+        self.is_optimal
     }
 
-    pub fn update_lower_bound(&mut self, _new_lower_bound: u64) {
-        todo!()
+    //lower bounds that are not better than the current lower bound are ignored
+    pub fn update_lower_bound(&mut self, new_lower_bound: u64) {
+        if new_lower_bound <= self.lower_bound {
+            return;
+        }
+        pumpkin_assert_simple!(
+            !self.has_solution() || new_lower_bound <= self.best_objective_value,
+            "The lower bound cannot exceed the objective value of a solution."
+        );
+
+        self.lower_bound = new_lower_bound;
+        println!("c lower bound {}", self.lower_bound);
+
+        if self.has_solution() && self.lower_bound == self.best_objective_value {
+            self.declare_optimal();
+        }
     }
 
-    pub fn update_solution(&mut self, _new_solution: &Solution) {
-        todo!();
+    //solutions that are not better than the best solution are ignored
+    pub fn update_solution(&mut self, new_solution: &Solution) {
+        let objective_value = self.objective_function.evaluate_solution(new_solution);
+        if self.has_solution() && objective_value >= self.best_objective_value {
+            return;
+        }
+        pumpkin_assert_simple!(
+            objective_value >= self.lower_bound,
+            "A solution cannot be better than the lower bound."
+        );
+
+        self.best_solution = Some(new_solution.clone());
+        self.best_objective_value = objective_value;
+        self.improvements.push(Improvement {
+            objective_value,
+            time: self.time_start.elapsed(),
+        });
+        println!("o {}", objective_value);
+        info!(
+            "Current objective is {} after {} seconds",
+            objective_value,
+            self.time_start.elapsed().as_secs()
+        );
+
+        if self.lower_bound == self.best_objective_value {
+            self.declare_optimal();
+        }
     }
 
     pub fn declare_optimal(&mut self) {
-        todo!();
+        pumpkin_assert_simple!(
+            self.has_solution(),
+            "Can only declare optimality once a solution has been found."
+        );
+        self.lower_bound = self.best_objective_value;
+        self.is_optimal = true;
     }
 }
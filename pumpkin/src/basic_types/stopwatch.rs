@@ -1,20 +1,21 @@
 use std::time::Instant;
 
+//time limits are kept in milliseconds so that budgets below one second can be expressed
 pub struct Stopwatch {
-    time_limit_in_seconds: i64,
+    time_limit_in_milliseconds: i64,
     time_start: Instant,
 }
 
 impl Stopwatch {
-    pub fn new(time_limit_in_seconds: i64) -> Stopwatch {
+    pub fn new(time_limit_in_milliseconds: i64) -> Stopwatch {
         Stopwatch {
-            time_limit_in_seconds,
+            time_limit_in_milliseconds,
             time_start: Instant::now(),
         }
     }
 
-    pub fn reset(&mut self, time_limit_in_seconds: i64) {
-        self.time_limit_in_seconds = time_limit_in_seconds;
+    pub fn reset(&mut self, time_limit_in_milliseconds: i64) {
+        self.time_limit_in_milliseconds = time_limit_in_milliseconds;
         self.time_start = Instant::now();
     }
 
@@ -22,7 +23,12 @@ impl Stopwatch {
         self.time_start.elapsed().as_secs()
     }
 
-    pub fn get_remaining_time_budget(&self) -> i64 {
-        self.time_limit_in_seconds - self.get_elapsed_time() as i64
+    pub fn get_elapsed_time_in_milliseconds(&self) -> u64 {
+        self.time_start.elapsed().as_millis() as u64
+    }
+
+    pub fn get_remaining_time_budget_in_milliseconds(&self) -> i64 {
+        self.time_limit_in_milliseconds
+            .saturating_sub(self.get_elapsed_time_in_milliseconds() as i64)
     }
 }
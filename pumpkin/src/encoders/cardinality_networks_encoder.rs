@@ -70,6 +70,29 @@ impl UpperBoundEncoder for CardinalityNetworkEncoder {
             EncodingStatus::NoConflictDetected
         }
     }
+
+    fn assumptions_for_at_most_k(
+        &mut self,
+        k: u64,
+        _csp_solver: &mut ConstraintSatisfactionSolver,
+    ) -> Option<Vec<Literal>> {
+        pumpkin_assert_simple!(
+            self.encoding_generated,
+            "The encoding needs to be generated before assumptions can be used."
+        );
+
+        if k < self.constant_term {
+            return None;
+        }
+
+        //the outputs are sorted, so forbidding the (k+1)-th true literal is enough
+        let k = (k - self.constant_term) as usize;
+        if k < self.output.len() {
+            Some(vec![!self.output[k]])
+        } else {
+            Some(vec![])
+        }
+    }
 }
 
 impl CardinalityNetworkEncoder {
@@ -353,7 +376,7 @@ fn even_literals(lits: &[Literal]) -> Vec<Literal> {
 
 #[cfg(test)]
 mod tests {
-    use crate::basic_types::Function;
+    use crate::basic_types::{CSPSolverExecutionFlag, Function};
 
     use super::*;
 
@@ -455,6 +478,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_assumptions_restrict_without_committing() {
+        let mut csp_solver = ConstraintSatisfactionSolver::default();
+        let xs = create_variables(&mut csp_solver, 4);
+        assert_eq!(
+            ClauseAdditionOutcome::NoConflictDetected,
+            csp_solver.add_permanent_clause(vec![xs[0], xs[1]])
+        );
+
+        let mut ub = CardinalityNetworkEncoder::new(xs.clone(), 0);
+        assert_eq!(
+            EncodingStatus::NoConflictDetected,
+            ub.constrain_at_most_k(3, &mut csp_solver)
+        );
+
+        let assumptions = ub.assumptions_for_at_most_k(0, &mut csp_solver).unwrap();
+        assert!(matches!(
+            csp_solver.solve_under_assumptions(&assumptions, i64::MAX),
+            CSPSolverExecutionFlag::InfeasibleUnderAssumptions
+        ));
+
+        let assumptions = ub.assumptions_for_at_most_k(1, &mut csp_solver).unwrap();
+        assert!(matches!(
+            csp_solver.solve_under_assumptions(&assumptions, i64::MAX),
+            CSPSolverExecutionFlag::Feasible
+        ));
+        let num_true = xs
+            .iter()
+            .filter(|x| {
+                csp_solver
+                    .get_propositional_assignments()
+                    .is_literal_assigned_true(**x)
+            })
+            .count();
+        assert_eq!(1, num_true);
+    }
+
     fn create_variables(csp_solver: &mut ConstraintSatisfactionSolver, n: usize) -> Vec<Literal> {
         let xs = std::iter::from_fn(|| Some(csp_solver.create_new_propositional_variable()))
             .map(|var| Literal::new(var, true))
@@ -17,6 +17,7 @@ pub struct GeneralisedTotaliserEncoder {
     initial_weighted_literals: Vec<WeightedLiteral>, //original weighted literals as provided by the input function (without any preprocessing)
     internal_k: u64,      //the 'k' value after subtracting the root fixed cost
     root_fixed_cost: u64, //internal value that represents the left hands side at the root level
+    constant_term: u64,
     index_last_added_weighted_literal: usize,
     layers: Vec<Layer>,
     num_clauses_added: usize,
@@ -33,6 +34,7 @@ impl GeneralisedTotaliserEncoder {
             initial_weighted_literals,
             internal_k: u64::MAX,
             root_fixed_cost: function.get_constant_term(),
+            constant_term: function.get_constant_term(),
             index_last_added_weighted_literal: usize::MAX,
             layers: vec![],
             num_clauses_added: 0,
@@ -61,6 +63,61 @@ impl GeneralisedTotaliserEncoder {
     }
 }
 
+impl GeneralisedTotaliserEncoder {
+    pub fn assumptions_for_at_most_k(
+        &mut self,
+        k: u64,
+        csp_solver: &mut ConstraintSatisfactionSolver,
+    ) -> Option<Vec<Literal>> {
+        pumpkin_assert_simple!(
+            self.internal_k != u64::MAX,
+            "The constraint needs to be encoded before assumptions can be used."
+        );
+        pumpkin_assert_simple!(
+            k <= self.root_fixed_cost + self.internal_k,
+            "Assumptions can only be used for values below the encoded k."
+        );
+
+        //the tree is not encoded if the constraint is too loose to need it, but the assumptions rely on it
+        if !self.has_encoding() {
+            pumpkin_assert_simple!(
+                csp_solver
+                    .get_propositional_assignments()
+                    .is_at_the_root_level(),
+                "Can only add encodings at the root level."
+            );
+
+            let input_k = self.root_fixed_cost + self.internal_k;
+            let mut processed_terms = self.initialise(input_k, csp_solver)?;
+            if processed_terms.is_empty() {
+                return if k >= self.root_fixed_cost {
+                    Some(vec![])
+                } else {
+                    None
+                };
+            }
+            processed_terms.sort_by_key(|p| p.weight);
+            self.encode_at_most_k_standard_case(processed_terms, csp_solver);
+        }
+
+        if k < self.root_fixed_cost {
+            return None;
+        }
+        let internal_k = k - self.root_fixed_cost;
+
+        //if the sum of the input literals is w, then the root literal with weight w is set to true
+        //  so all sums above k are excluded by assuming the corresponding literals are false
+        //  the literals past 'index_last_added_weighted_literal' are already false at the root
+        Some(
+            self.layers.last().unwrap().nodes[0][..self.index_last_added_weighted_literal]
+                .iter()
+                .filter(|weighted_literal| weighted_literal.weight > internal_k)
+                .map(|weighted_literal| !weighted_literal.literal)
+                .collect(),
+        )
+    }
+}
+
 impl GeneralisedTotaliserEncoder {
     fn encode_at_most_k(
         &mut self,
@@ -109,7 +166,7 @@ impl GeneralisedTotaliserEncoder {
         }
         //a good heuristic is to sort the literals by weight with a stable ordering
         //  this reduces the size of the encoding significantly
-        processed_terms.sort_by(|p1, p2| p1.weight.cmp(&p2.weight));
+        processed_terms.sort_by_key(|p| p.weight);
 
         //standard case
         self.encode_at_most_k_standard_case(processed_terms, csp_solver);
@@ -326,43 +383,52 @@ impl GeneralisedTotaliserEncoder {
         //  literals that evaluate to true can be removed from consideration, but the k value needs to be updated accordingly
 
         //  here we compute the left hand side value cause by literals assigned to true at the root level
-        //      note: during initialisation, we took into account the constant term of the input function (see 'new'), so here we only need to add the root cost associated with root literals
-        self.root_fixed_cost += self
-            .initial_weighted_literals
-            .iter()
-            .filter_map(|p| {
-                if csp_solver
-                    .get_propositional_assignments()
-                    .is_literal_assigned_true(p.literal)
-                {
-                    Some(p.weight)
-                } else {
-                    None
-                }
-            })
-            .sum::<u64>();
-
-        //  if the violations at the root make the constraint infeasible, report and stop
-        if self.root_fixed_cost > input_k {
-            return None;
-        }
-        //k is then updated to take into account the root fixed cost
-        self.internal_k = input_k - self.root_fixed_cost;
+        //  forbidding terms that exceed k may propagate other terms to true at the root, so this is repeated until nothing changes
+        loop {
+            self.root_fixed_cost = self.constant_term
+                + self
+                    .initial_weighted_literals
+                    .iter()
+                    .filter_map(|p| {
+                        if csp_solver
+                            .get_propositional_assignments()
+                            .is_literal_assigned_true(p.literal)
+                        {
+                            Some(p.weight)
+                        } else {
+                            None
+                        }
+                    })
+                    .sum::<u64>();
 
-        //propagate unassigned terms whose violation would exceed k
-        for term in &self.initial_weighted_literals {
-            if term.weight > self.internal_k
-                && csp_solver
-                    .get_propositional_assignments()
-                    .is_literal_unassigned(term.literal)
-            {
-                let status = csp_solver.add_unit_clause(!term.literal);
-                self.num_clauses_added += 1;
+            //  if the violations at the root make the constraint infeasible, report and stop
+            if self.root_fixed_cost > input_k {
+                return None;
+            }
+            //k is then updated to take into account the root fixed cost
+            self.internal_k = input_k - self.root_fixed_cost;
+
+            //propagate unassigned terms whose violation would exceed k
+            let mut propagated_terms = false;
+            for term in &self.initial_weighted_literals {
+                if term.weight > self.internal_k
+                    && csp_solver
+                        .get_propositional_assignments()
+                        .is_literal_unassigned(term.literal)
+                {
+                    let status = csp_solver.add_unit_clause(!term.literal);
+                    self.num_clauses_added += 1;
+                    propagated_terms = true;
 
-                if let ClauseAdditionOutcome::Infeasible = status {
-                    return None;
+                    if let ClauseAdditionOutcome::Infeasible = status {
+                        return None;
+                    }
                 }
             }
+
+            if !propagated_terms {
+                break;
+            }
         }
 
         //remove literals assigned at the root from consideration
@@ -391,12 +457,14 @@ impl GeneralisedTotaliserEncoder {
         //note that there is a discrepancy between new_k (k given as input) and the internal_k
         //  the internal_k is computed as input_k - unavoidable_violations
         //  recall that unavoidable violations refer to the constant term in the original function and any violations at the root level
+        if new_k < self.root_fixed_cost {
+            return EncodingStatus::ConflictDetected;
+        }
         pumpkin_assert_simple!(
             self.has_encoding() && new_k - self.root_fixed_cost < self.internal_k,
             "We expect k will be strictly decreasing!"
         );
         pumpkin_assert_simple!(self.index_last_added_weighted_literal > 0);
-        pumpkin_assert_simple!(self.root_fixed_cost <= new_k); //not an error per-se but in the current implementation it would be odd to have this assert fail
         pumpkin_assert_simple!(
             !self.layers.is_empty() && self.layers.last().unwrap().nodes.len() == 1
         );
@@ -442,3 +510,61 @@ impl Layer {
         Layer { nodes: vec![] }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic_types::CSPSolverExecutionFlag;
+
+    #[test]
+    fn test_terms_propagated_at_the_root_count_towards_k() {
+        let mut csp_solver = ConstraintSatisfactionSolver::default();
+        let xs: Vec<Literal> = csp_solver.new_literals().take(3).collect();
+        assert_eq!(
+            ClauseAdditionOutcome::NoConflictDetected,
+            csp_solver.add_permanent_clause(vec![xs[0], xs[1]])
+        );
+        let mut function = Function::new();
+        function.add_weighted_literal(xs[0], 5);
+        function.add_weighted_literal(xs[1], 3);
+        function.add_weighted_literal(xs[2], 2);
+
+        //x0 exceeds k on its own, so x1 is propagated and only 1 is left for x2
+        let mut encoder = GeneralisedTotaliserEncoder::new(&function, &csp_solver);
+        assert_eq!(
+            EncodingStatus::NoConflictDetected,
+            encoder.constrain_at_most_k(4, &mut csp_solver)
+        );
+        assert_eq!(
+            ClauseAdditionOutcome::Infeasible,
+            csp_solver.add_unit_clause(xs[2])
+        );
+    }
+
+    #[test]
+    fn test_assumptions_on_a_loose_constraint() {
+        let mut csp_solver = ConstraintSatisfactionSolver::default();
+        let xs: Vec<Literal> = csp_solver.new_literals().take(3).collect();
+        let mut function = Function::new();
+        for (x, weight) in xs.iter().zip([1, 2, 3]) {
+            function.add_weighted_literal(*x, weight);
+        }
+
+        let mut encoder = GeneralisedTotaliserEncoder::new(&function, &csp_solver);
+        assert_eq!(
+            EncodingStatus::NoConflictDetected,
+            encoder.constrain_at_most_k(6, &mut csp_solver)
+        );
+
+        let assumptions = encoder
+            .assumptions_for_at_most_k(2, &mut csp_solver)
+            .unwrap();
+        assert!(matches!(
+            csp_solver.solve_under_assumptions(&[assumptions, vec![xs[1]]].concat(), i64::MAX),
+            CSPSolverExecutionFlag::Feasible
+        ));
+        let assignments = csp_solver.get_propositional_assignments();
+        assert!(assignments.is_literal_assigned_false(xs[0]));
+        assert!(assignments.is_literal_assigned_false(xs[2]));
+    }
+}
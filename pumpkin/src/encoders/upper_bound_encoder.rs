@@ -1,4 +1,4 @@
-use crate::engine::ConstraintSatisfactionSolver;
+use crate::{basic_types::Literal, engine::ConstraintSatisfactionSolver};
 
 use super::GeneralisedTotaliserEncoder;
 
@@ -11,6 +11,16 @@ pub trait UpperBoundEncoder {
         k: u64,
         csp_solver: &mut ConstraintSatisfactionSolver,
     ) -> EncodingStatus;
+
+    /// Get literals which, when assumed, restrict the objective function to at most k without
+    /// committing to that bound. The encoding must already exist, i.e. `constrain_at_most_k`
+    /// has been called with a value of at least k. Returns None if the objective function
+    /// cannot be at most k, regardless of the assumptions.
+    fn assumptions_for_at_most_k(
+        &mut self,
+        k: u64,
+        csp_solver: &mut ConstraintSatisfactionSolver,
+    ) -> Option<Vec<Literal>>;
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -30,4 +40,12 @@ impl UpperBoundEncoder for GeneralisedTotaliserEncoder {
     ) -> EncodingStatus {
         self.constrain_at_most_k(k, csp_solver)
     }
+
+    fn assumptions_for_at_most_k(
+        &mut self,
+        k: u64,
+        csp_solver: &mut ConstraintSatisfactionSolver,
+    ) -> Option<Vec<Literal>> {
+        self.assumptions_for_at_most_k(k, csp_solver)
+    }
 }
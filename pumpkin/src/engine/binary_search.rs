@@ -0,0 +1,150 @@
+use crate::{
+    basic_types::{CSPSolverExecutionFlag, Function, Solution, SolutionTracker, Stopwatch},
+    encoders::{EncodingStatus, UpperBoundEncoder},
+    pumpkin_asserts::{pumpkin_assert_moderate, pumpkin_assert_simple},
+};
+
+use super::{linear_search::UpperBoundEncoding, ConstraintSatisfactionSolver};
+
+//optimisation by querying bounds between the lower bound and the best solution found so far
+//  each query asks for a solution of at most k by assuming the output literals of the upper bound encoding
+//  a solution becomes the new best solution and its bound is added permanently, while an infeasible query raises the lower bound to k+1
+//  since queries are answered under assumptions, the clauses learned along the way remain valid for the following queries
+pub struct BinarySearch {
+    upper_bound_encoding: UpperBoundEncoding,
+    bound_selection: BoundSelection,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoundSelection {
+    /// Query the middle of the lower bound and the best solution
+    Bisection,
+    /// Query the lower bound plus 0, 1, 3, 7, ..., doubling the step after each infeasible query
+    Progression,
+}
+
+impl BinarySearch {
+    pub fn new(
+        upper_bound_encoding: UpperBoundEncoding,
+        bound_selection: BoundSelection,
+    ) -> BinarySearch {
+        BinarySearch {
+            upper_bound_encoding,
+            bound_selection,
+        }
+    }
+
+    pub fn solve(
+        &self,
+        csp_solver: &mut ConstraintSatisfactionSolver,
+        objective_function: &Function,
+        stopwatch: &Stopwatch,
+        solution_tracker: &mut SolutionTracker,
+    ) {
+        pumpkin_assert_simple!(
+            csp_solver.get_state().has_solution() && solution_tracker.has_solution(),
+            "Binary search assumes the solver contains a feasible solution."
+        );
+
+        let mut upper_bound_encoder = self
+            .upper_bound_encoding
+            .create_encoder(objective_function, csp_solver);
+        //the step of the progression, which is reset every time a solution is found
+        let mut step = 1;
+
+        if !BinarySearch::constrain_upper_bound(
+            upper_bound_encoder.as_mut(),
+            csp_solver,
+            solution_tracker,
+        ) {
+            return;
+        }
+
+        loop {
+            let lower_bound = solution_tracker.get_lower_bound();
+            let best_objective_value = solution_tracker.get_best_objective_value();
+            let k = match self.bound_selection {
+                BoundSelection::Bisection => lower_bound + (best_objective_value - lower_bound) / 2,
+                BoundSelection::Progression => lower_bound + step - 1,
+            };
+            //the best solution itself is excluded by the permanent bound
+            let k = k.min(best_objective_value - 1);
+
+            let csp_execution_flag =
+                match upper_bound_encoder.assumptions_for_at_most_k(k, csp_solver) {
+                    Some(assumptions) => csp_solver.solve_under_assumptions(
+                        &assumptions,
+                        stopwatch.get_remaining_time_budget_in_milliseconds(),
+                    ),
+                    None => CSPSolverExecutionFlag::InfeasibleUnderAssumptions,
+                };
+
+            match csp_execution_flag {
+                CSPSolverExecutionFlag::Feasible => {
+                    pumpkin_assert_moderate!(
+                        objective_function.evaluate_assignment(
+                            csp_solver.get_propositional_assignments(),
+                            csp_solver.get_integer_assignments(),
+                        ) <= k,
+                        "A solution found under the assumptions must respect the queried bound."
+                    );
+
+                    solution_tracker.update_solution(&Solution::new(
+                        csp_solver.get_propositional_assignments(),
+                        csp_solver.get_integer_assignments(),
+                    ));
+                    step = 1;
+
+                    if !BinarySearch::constrain_upper_bound(
+                        upper_bound_encoder.as_mut(),
+                        csp_solver,
+                        solution_tracker,
+                    ) {
+                        return;
+                    }
+                }
+                CSPSolverExecutionFlag::InfeasibleUnderAssumptions => {
+                    solution_tracker.update_lower_bound(k + 1);
+                    step *= 2;
+
+                    if solution_tracker.has_optimal_solution() {
+                        return;
+                    }
+                    //the encoder may need to extend the encoding for the next query
+                    if csp_solver.get_state().is_infeasible_under_assumptions() {
+                        csp_solver.restore_state_at_root();
+                    }
+                }
+                //the permanent bound excludes every solution better than the best solution
+                CSPSolverExecutionFlag::Infeasible => {
+                    solution_tracker.declare_optimal();
+                    return;
+                }
+                CSPSolverExecutionFlag::Timeout => {
+                    return;
+                }
+            }
+        }
+    }
+
+    //the best solution is only ever improved, so its bound can be added permanently
+    //  returns false if the search is done, i.e., this proves that the best solution is optimal
+    fn constrain_upper_bound(
+        upper_bound_encoder: &mut dyn UpperBoundEncoder,
+        csp_solver: &mut ConstraintSatisfactionSolver,
+        solution_tracker: &mut SolutionTracker,
+    ) -> bool {
+        if solution_tracker.has_optimal_solution() {
+            return false;
+        }
+        csp_solver.set_solution_guided_search();
+        csp_solver.restore_state_at_root();
+        let encoding_status = upper_bound_encoder
+            .constrain_at_most_k(solution_tracker.get_best_objective_value() - 1, csp_solver);
+        if encoding_status == EncodingStatus::ConflictDetected {
+            solution_tracker.declare_optimal();
+            return false;
+        }
+        true
+    }
+}
@@ -1,5 +1,5 @@
 use super::cp::CPEngineDataStructures;
-use super::sat::SATEngineDataStructures;
+use super::sat::{Certificate, SATEngineDataStructures, VariableOrderingHeuristic};
 use super::{
     AssignmentsInteger, AssignmentsPropositional, SATCPMediator,
     SATDataStructuresInternalParameters,
@@ -11,11 +11,13 @@ use crate::basic_types::{
     PropositionalVariable, Stopwatch,
 };
 use crate::engine::{DebugHelper, DomainManager};
+
+use super::restart_strategy::{RestartStrategy, Restarter};
 use crate::propagators::ConstraintProgrammingPropagator;
 use crate::pumpkin_asserts::*;
-use log::warn;
 use std::fs::File;
-use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 pub struct ConstraintSatisfactionSolver {
     state: CSPSolverState,
@@ -25,33 +27,67 @@ pub struct ConstraintSatisfactionSolver {
     sat_cp_mediator: SATCPMediator,
     seen: Vec<bool>,
     counters: Counters,
+    restarter: Restarter,
     internal_parameters: SatisfactionSolverOptions,
     stopwatch: Stopwatch,
+    termination_flag: Arc<AtomicBool>,
+    call_limits: CallLimits,
 }
 
 pub struct SatisfactionSolverOptions {
-    /// The number of conflicts after which a restart is triggered.
+    /// Decides when restarts are triggered.
+    pub restart_strategy: RestartStrategy,
+    /// The number of conflicts after which a restart is triggered for the fixed restart strategy,
+    /// or the unit that the Luby sequence is multiplied with for the Luby restart strategy.
     pub conflicts_per_restart: i64,
     /// Certificate output file or None if certificate output is disabled.
     pub certificate_file: Option<File>,
+    /// Whether learned clauses are minimised by removing literals implied by the other literals.
+    pub use_clause_minimisation: bool,
+    /// The number of conflicts between two rounds of inprocessing, or None if inprocessing is disabled.
+    pub conflicts_per_inprocessing: Option<u64>,
+    /// The number of conflicts, counted over all calls, after which the solver stops, or None if unlimited.
+    pub conflict_limit: Option<u64>,
+    /// The number of propagations, counted over all calls, after which the solver stops, or None if unlimited.
+    pub propagation_limit: Option<u64>,
+}
+
+/// Limits for a single call to the solver, counted from the start of that call. They apply on top
+/// of the limits in [`SatisfactionSolverOptions`], which are counted over all calls.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CallLimits {
+    /// The number of conflicts in this call after which the solver stops, or None if unlimited.
+    pub conflicts: Option<u64>,
+    /// The number of propagations in this call after which the solver stops, or None if unlimited.
+    pub propagations: Option<u64>,
 }
 
 //methods that offer basic functionality
 impl ConstraintSatisfactionSolver {
     pub fn new(
         sat_options: SATDataStructuresInternalParameters,
-        solver_options: SatisfactionSolverOptions,
+        mut solver_options: SatisfactionSolverOptions,
     ) -> ConstraintSatisfactionSolver {
+        //the certificate is kept with the clauses, so that clause deletions can be logged as well
+        let mut sat_data_structures = SATEngineDataStructures::new(sat_options);
+        sat_data_structures.certificate = Certificate::new(solver_options.certificate_file.take());
+
         let mut csp_solver = ConstraintSatisfactionSolver {
             state: CSPSolverState::new(),
-            sat_data_structures: SATEngineDataStructures::new(sat_options),
+            sat_data_structures,
             cp_data_structures: CPEngineDataStructures::new(),
             cp_propagators: vec![],
             sat_cp_mediator: SATCPMediator::new(),
             seen: vec![],
-            counters: Counters::new(solver_options.conflicts_per_restart),
+            counters: Counters::new(),
+            restarter: Restarter::new(
+                solver_options.restart_strategy,
+                solver_options.conflicts_per_restart.max(0) as u64,
+            ),
             internal_parameters: solver_options,
             stopwatch: Stopwatch::new(i64::MAX),
+            termination_flag: Arc::new(AtomicBool::new(false)),
+            call_limits: CallLimits::default(),
         };
 
         //we introduce a dummy variable set to true at the root level
@@ -83,30 +119,145 @@ impl ConstraintSatisfactionSolver {
     pub fn solve_under_assumptions(
         &mut self,
         assumptions: &[Literal],
-        time_limit_in_seconds: i64,
+        time_limit_in_milliseconds: i64,
     ) -> CSPSolverExecutionFlag {
-        self.initialise(assumptions, time_limit_in_seconds);
+        self.solve_under_assumptions_with_limits(
+            assumptions,
+            time_limit_in_milliseconds,
+            CallLimits::default(),
+        )
+    }
+
+    //as solve_under_assumptions, but the call also stops with a timeout once it exhausts the given limits
+    //  this lets a single query be bounded, e.g., by an optimisation strategy or an external driver
+    pub fn solve_under_assumptions_with_limits(
+        &mut self,
+        assumptions: &[Literal],
+        time_limit_in_milliseconds: i64,
+        call_limits: CallLimits,
+    ) -> CSPSolverExecutionFlag {
+        self.initialise(assumptions, time_limit_in_milliseconds, call_limits);
         self.solve_internal()
     }
 
+    //returns a subset of the assumptions that cannot be satisfied together, which always contains the violated assumption
+    //  the core is computed by going back over the trail from the violated assumption, and collecting the assumptions that led to its falsification
     pub fn extract_core(&mut self) -> Vec<Literal> {
         pumpkin_assert_simple!(
             self.state.is_infeasible_under_assumptions(),
             "Cannot extract core unless the solver is in the infeasible under assumption state."
         );
-        todo!();
+
+        let violated_assumption = self.state.get_violated_assumption();
+        let mut core = vec![violated_assumption];
+
+        //the assumption is falsified at the root, so it is a core on its own
+        if self
+            .sat_data_structures
+            .assignments_propositional
+            .is_literal_root_assignment(violated_assumption)
+        {
+            return core;
+        }
+
+        self.seen[violated_assumption.get_propositional_variable()] = true;
+        for trail_index in (0..self
+            .sat_data_structures
+            .assignments_propositional
+            .trail
+            .len())
+            .rev()
+        {
+            let literal = self.sat_data_structures.assignments_propositional.trail[trail_index];
+            let variable = literal.get_propositional_variable();
+            //the remaining literals are root assignments, which do not depend on assumptions
+            if self
+                .sat_data_structures
+                .assignments_propositional
+                .is_literal_root_assignment(literal)
+            {
+                break;
+            }
+
+            if !self.seen[variable] {
+                continue;
+            }
+            self.seen[variable] = false;
+
+            //only assumptions have been decided at this point, so any decision is part of the core
+            if !self
+                .sat_data_structures
+                .assignments_propositional
+                .is_variable_propagated(variable)
+            {
+                core.push(literal);
+                continue;
+            }
+
+            let reason_clause_reference = self
+                .sat_cp_mediator
+                .get_propagation_reason_clause_reference(
+                    literal,
+                    &mut self.sat_data_structures,
+                    &self.cp_data_structures,
+                    &mut self.cp_propagators,
+                );
+            //the zero-th literal of the reason is the propagated literal itself
+            for index in 1..self.sat_data_structures.clause_allocator[reason_clause_reference].len()
+            {
+                let reason_literal =
+                    self.sat_data_structures.clause_allocator[reason_clause_reference][index];
+                if !self
+                    .sat_data_structures
+                    .assignments_propositional
+                    .is_literal_root_assignment(reason_literal)
+                {
+                    self.seen[reason_literal.get_propositional_variable()] = true;
+                }
+            }
+        }
+
+        //literals above the root that were not reached still need their flag cleared
+        for literal in self
+            .sat_data_structures
+            .assignments_propositional
+            .trail
+            .iter()
+        {
+            self.seen[literal.get_propositional_variable()] = false;
+        }
+        self.sat_data_structures.clean_up_explanation_clauses();
+
+        core
+    }
+
+    pub fn solve(&mut self, time_limit_in_milliseconds: i64) -> CSPSolverExecutionFlag {
+        self.solve_with_limits(time_limit_in_milliseconds, CallLimits::default())
     }
 
-    pub fn solve(&mut self, time_limit_in_seconds: i64) -> CSPSolverExecutionFlag {
+    pub fn solve_with_limits(
+        &mut self,
+        time_limit_in_milliseconds: i64,
+        call_limits: CallLimits,
+    ) -> CSPSolverExecutionFlag {
         let dummy_assumptions: Vec<Literal> = vec![];
-        self.solve_under_assumptions(&dummy_assumptions, time_limit_in_seconds)
+        self.solve_under_assumptions_with_limits(
+            &dummy_assumptions,
+            time_limit_in_milliseconds,
+            call_limits,
+        )
     }
 
-    pub fn reset_variable_selection(&mut self, random_seed: i64) {
+    pub fn reset_variable_selection(&mut self, heuristic: &VariableOrderingHeuristic) {
         pumpkin_assert_simple!(self.state.is_ready());
         self.sat_data_structures
             .propositional_variable_selector
-            .reset(random_seed);
+            .reset(heuristic);
+    }
+
+    //once the flag is set, the solver stops at the next iteration as if the time limit was reached
+    pub fn set_termination_flag(&mut self, termination_flag: Arc<AtomicBool>) {
+        self.termination_flag = termination_flag;
     }
 
     pub fn get_state(&self) -> &CSPSolverState {
@@ -185,8 +336,20 @@ impl ConstraintSatisfactionSolver {
         }
     }
 
+    //unlike fixed phases, these are only the values tried first, after which phase saving takes over
+    pub fn set_initial_phases_for_variables(&mut self, literals: &[Literal]) {
+        for literal in literals {
+            self.sat_data_structures
+                .propositional_value_selector
+                .update_if_not_frozen(literal.get_propositional_variable(), literal.is_positive());
+        }
+    }
+
     pub fn restore_state_at_root(&mut self) {
-        pumpkin_assert_simple!(self.state.has_solution() && self.get_decision_level() > 0);
+        pumpkin_assert_simple!(
+            (self.state.has_solution() || self.state.is_infeasible_under_assumptions())
+                && self.get_decision_level() > 0
+        );
 
         self.backtrack(0);
         self.state.declare_ready();
@@ -195,23 +358,50 @@ impl ConstraintSatisfactionSolver {
 
 //methods that serve as the main building blocks
 impl ConstraintSatisfactionSolver {
-    fn initialise(&mut self, assumptions: &[Literal], time_limit_in_seconds: i64) {
+    fn initialise(
+        &mut self,
+        assumptions: &[Literal],
+        time_limit_in_milliseconds: i64,
+        call_limits: CallLimits,
+    ) {
         let num_propositional_variables = self
             .sat_data_structures
             .assignments_propositional
             .num_propositional_variables() as usize;
 
+        //the solver may be called again after it returned a solution or a core without being restored
+        //  learned clauses are kept, only the assignments above the root are undone
+        if self.state.has_solution() || self.state.is_infeasible_under_assumptions() {
+            self.restore_state_at_root();
+        }
+        //likewise after a call that ran out of its budget, so that a query can be continued with a new budget
+        //  the timeout may have been reached at the root, in which case there is nothing to undo
+        if self.state.timeout() {
+            if self.get_decision_level() > 0 {
+                self.backtrack(0);
+            }
+            self.state.declare_ready();
+        }
+
         self.state.declare_solving();
-        self.stopwatch.reset(time_limit_in_seconds);
+        self.stopwatch.reset(time_limit_in_milliseconds);
+        self.call_limits = call_limits;
+        self.counters.num_conflicts_at_call_start = self.counters.num_conflicts;
+        self.counters.num_propagations_at_call_start = self.counters.num_propagations;
         self.sat_data_structures.assumptions = assumptions.to_owned();
         self.seen.resize(num_propositional_variables, false);
 
-        self.counters.num_conflicts_until_restart = self.internal_parameters.conflicts_per_restart;
+        self.restarter.notify_backtrack_to_root();
     }
 
     fn solve_internal(&mut self) -> CSPSolverExecutionFlag {
         loop {
-            if self.stopwatch.get_remaining_time_budget() <= 0 {
+            //the termination flag is set from outside the solver, e.g., when the process receives a signal
+            //  stopping is then treated the same as running out of time, as is exhausting any of the other budgets
+            if self.stopwatch.get_remaining_time_budget_in_milliseconds() <= 0
+                || self.termination_flag.load(Ordering::Relaxed)
+                || self.is_budget_exhausted()
+            {
                 self.state.declare_timeout();
                 return CSPSolverExecutionFlag::Timeout;
             }
@@ -220,9 +410,20 @@ impl ConstraintSatisfactionSolver {
 
             if self.state.no_conflict() {
                 if self.should_restart() {
+                    self.restarter.notify_restart();
+                    self.sat_data_structures
+                        .propositional_variable_selector
+                        .notify_restart();
                     self.backtrack(0);
                 }
 
+                //inprocessing may enqueue new root assignments, which are propagated before continuing
+                if self.should_inprocess() {
+                    self.sat_data_structures.inprocess();
+                    self.counters.num_conflicts_at_last_inprocessing = self.counters.num_conflicts;
+                    continue;
+                }
+
                 self.sat_data_structures
                     .assignments_propositional
                     .increase_decision_level();
@@ -256,17 +457,12 @@ impl ConstraintSatisfactionSolver {
                             }
                             //Case 3: the assumption literal is in conflict with the input assumption
                             //  which means the instance is infeasible under the current assumptions
+                            //  the literal is falsified at the root, propagated by earlier assumptions, or its negation is an earlier assumption
                             else {
-                                pumpkin_assert_moderate!(
-                                    self.sat_data_structures
-                                        .assignments_propositional
-                                        .get_literal_assignment_level(assumption_literal)
-                                        == 0
-                                        || self
-                                            .sat_data_structures
-                                            .assignments_propositional
-                                            .is_literal_propagated(assumption_literal),
-                                );
+                                pumpkin_assert_moderate!(self
+                                    .sat_data_structures
+                                    .assignments_propositional
+                                    .is_literal_assigned_false(assumption_literal));
 
                                 self.state
                                     .declare_infeasible_under_assumptions(assumption_literal);
@@ -311,35 +507,48 @@ impl ConstraintSatisfactionSolver {
         }
     }
 
-    fn write_to_certificate(
-        &mut self,
-        analysis_result: &ConflictAnalysisResult,
-    ) -> std::io::Result<()> {
-        if let Some(cert_file) = &mut self.internal_parameters.certificate_file {
-            for lit in &analysis_result.learned_literals {
-                if lit.is_negative() {
-                    cert_file.write_all("-".as_bytes())?;
-                }
-                cert_file.write_all(
-                    format!("{} ", &lit.get_propositional_variable().index().to_string())
-                        .as_bytes(),
-                )?;
-            }
-            cert_file.write_all("0\n".as_bytes())?;
-        }
-        Ok(())
+    //the conflict and propagation limits do not depend on the machine, unlike the time limit, which makes runs reproducible
+    //  the limits of the options are counted over all calls, and the limits of the call from the start of the call
+    fn is_budget_exhausted(&self) -> bool {
+        let num_conflicts_in_call =
+            self.counters.num_conflicts - self.counters.num_conflicts_at_call_start;
+        let num_propagations_in_call =
+            self.counters.num_propagations - self.counters.num_propagations_at_call_start;
+        self.internal_parameters
+            .conflict_limit
+            .is_some_and(|limit| self.counters.num_conflicts >= limit)
+            || self
+                .internal_parameters
+                .propagation_limit
+                .is_some_and(|limit| self.counters.num_propagations >= limit)
+            || self
+                .call_limits
+                .conflicts
+                .is_some_and(|limit| num_conflicts_in_call >= limit)
+            || self
+                .call_limits
+                .propagations
+                .is_some_and(|limit| num_propagations_in_call >= limit)
     }
 
     //changes the state based on the conflict analysis result given as input
     //i.e., adds the learned clause to the database, backtracks, enqueues the propagated literal, and updates internal data structures for simple moving averages
     //note that no propagation is done, this is left to the solver
     fn process_conflict_analysis_result(&mut self, analysis_result: ConflictAnalysisResult) {
-        if let Err(write_error) = self.write_to_certificate(&analysis_result) {
-            warn!(
-                "Failed to update the certificate file, error message: {}",
-                write_error
-            );
-        }
+        self.sat_data_structures
+            .certificate
+            .add_clause(&analysis_result.learned_literals);
+
+        //the restarter is notified before backtracking, while all learned literals are still assigned
+        let lbd = self
+            .sat_data_structures
+            .compute_lbd_for_literals(&analysis_result.learned_literals);
+        let num_assigned_literals = self
+            .sat_data_structures
+            .assignments_propositional
+            .num_assigned_propositional_variables() as u64;
+        self.restarter.notify_conflict(lbd, num_assigned_literals);
+
         //unit clauses are treated in a special way: they are added as decision literals at decision level 0
         if analysis_result.learned_literals.len() == 1 {
             self.backtrack(0);
@@ -354,18 +563,14 @@ impl ConstraintSatisfactionSolver {
             self.sat_data_structures
                 .assignments_propositional
                 .enqueue_decision_literal(unit_clause);
-            //state_.UpdateMovingAveragesForRestarts(1);
         } else {
-            //int lbd = state_.ComputeLBD(&analysis_result_.learned_clause_literals[0] + 1, analysis_result_.learned_clause_literals.size() - 1);
-            //state_.UpdateMovingAveragesForRestarts(lbd);
-
             self.backtrack(analysis_result.backjump_level);
 
             let propagated_literal = analysis_result.learned_literals[0];
 
             let learned_clause_reference = self
                 .sat_data_structures
-                .add_clause_unchecked(analysis_result.learned_literals, true);
+                .add_learned_clause_unchecked(analysis_result.learned_literals, lbd);
 
             self.sat_data_structures
                 .assignments_propositional
@@ -392,10 +597,20 @@ impl ConstraintSatisfactionSolver {
     }
 
     fn should_restart(&self) -> bool {
-        pumpkin_assert_moderate!(
-            self.counters.num_conflicts_until_restart > 0 || self.get_decision_level() > 0
-        );
-        self.counters.num_conflicts_until_restart <= 0
+        //there is nothing to restart at the root, e.g., right after learning a unit clause
+        self.get_decision_level() > 0 && self.restarter.should_restart()
+    }
+
+    //inprocessing is only done at the root, so it waits for the next restart once enough conflicts have passed
+    fn should_inprocess(&self) -> bool {
+        self.get_decision_level() == 0
+            && self
+                .internal_parameters
+                .conflicts_per_inprocessing
+                .is_some_and(|conflicts_per_inprocessing| {
+                    self.counters.num_conflicts - self.counters.num_conflicts_at_last_inprocessing
+                        >= conflicts_per_inprocessing
+                })
     }
 
     fn is_conflict_clause_set(&self) -> bool {
@@ -428,8 +643,7 @@ impl ConstraintSatisfactionSolver {
             self.sat_data_structures
                 .shrink_learned_clause_database_if_needed();
 
-            self.counters.num_conflicts_until_restart =
-                self.internal_parameters.conflicts_per_restart;
+            self.restarter.notify_backtrack_to_root();
 
             self.counters.num_restarts += 1;
         }
@@ -575,20 +789,133 @@ impl ConstraintSatisfactionSolver {
         }
         analysis_result.learned_literals[0] = !next_literal.unwrap();
 
+        //at this point, the seen flags are set exactly for the literals of the learned clause except the asserting literal
+        //  minimisation relies on this
+        if self.internal_parameters.use_clause_minimisation {
+            self.minimise_learned_clause(&mut analysis_result);
+        }
+
         //clear the seen flags for literals in the learned clause, since these were not cleaned up above
         for literal in &analysis_result.learned_literals {
             self.seen[literal.get_propositional_variable()] = false;
         }
 
+        //explanation clauses are cleaned up only now, since minimisation may also ask for explanations
         self.sat_data_structures.clean_up_explanation_clauses();
 
-        //pumpkin_assert_advanced(analysis_result.CheckCorrectnessAfterConflictAnalysis(state_), "There is an issues with the derived learned clause after analysis!");
-        //if (internal_parameters_.use_clause_minimisation_) { learned_clause_minimiser_.RemoveImplicationGraphDominatedLiteralsBetter(analysis_result); }
-        //pumpkin_assert_advanced(analysis_result.CheckCorrectnessAfterConflictAnalysis(state_), "After minimisation the learned clause has an issue!");
-
         analysis_result
     }
 
+    //removes literals from the learned clause that are implied by the other literals of the learned clause, as in MiniSat
+    //  a literal is redundant if all literals in its reason are either in the learned clause or are themselves redundant
+    fn minimise_learned_clause(&mut self, analysis_result: &mut ConflictAnalysisResult) {
+        //an abstraction of the decision levels in the learned clause, used to quickly stop the search once a literal is reached from a level outside of the clause
+        let abstract_levels = analysis_result.learned_literals[1..]
+            .iter()
+            .fold(0, |abstract_levels, literal| {
+                abstract_levels | self.get_abstract_level(*literal)
+            });
+
+        //the literals that were marked seen during the redundancy checks, including those in the original learned clause
+        let mut literals_to_clear = analysis_result.learned_literals[1..].to_vec();
+
+        let mut next_location = 1;
+        for index in 1..analysis_result.learned_literals.len() {
+            let literal = analysis_result.learned_literals[index];
+            //decisions, including assumptions, cannot be removed
+            if !self
+                .sat_data_structures
+                .assignments_propositional
+                .is_literal_propagated(literal)
+                || !self.is_literal_redundant(literal, abstract_levels, &mut literals_to_clear)
+            {
+                analysis_result.learned_literals[next_location] = literal;
+                next_location += 1;
+            }
+        }
+        analysis_result.learned_literals.truncate(next_location);
+
+        for literal in literals_to_clear {
+            self.seen[literal.get_propositional_variable()] = false;
+        }
+
+        //the literal with the highest decision level needs to be at index one, and determines the backjump level
+        analysis_result.backjump_level = 0;
+        for index in 1..analysis_result.learned_literals.len() {
+            let decision_level = self
+                .sat_data_structures
+                .assignments_propositional
+                .get_literal_assignment_level(analysis_result.learned_literals[index]);
+            if decision_level > analysis_result.backjump_level {
+                analysis_result.backjump_level = decision_level;
+                analysis_result.learned_literals.swap(1, index);
+            }
+        }
+    }
+
+    //literals found to be redundant keep their seen flag set, so that later checks can reuse the result
+    //  if the literal is not redundant, the seen flags set during this check are undone
+    fn is_literal_redundant(
+        &mut self,
+        literal: Literal,
+        abstract_levels: u32,
+        literals_to_clear: &mut Vec<Literal>,
+    ) -> bool {
+        let num_literals_to_clear = literals_to_clear.len();
+        let mut stack = vec![literal];
+        while let Some(next_literal) = stack.pop() {
+            let reason_clause_reference = self
+                .sat_cp_mediator
+                .get_propagation_reason_clause_reference(
+                    !next_literal,
+                    &mut self.sat_data_structures,
+                    &self.cp_data_structures,
+                    &mut self.cp_propagators,
+                );
+
+            //the zero-th literal of the reason is the propagated literal, the others are the reasons for the propagation
+            for index in 1..self.sat_data_structures.clause_allocator[reason_clause_reference].len()
+            {
+                let reason_literal =
+                    self.sat_data_structures.clause_allocator[reason_clause_reference][index];
+                if self.seen[reason_literal.get_propositional_variable()]
+                    || self
+                        .sat_data_structures
+                        .assignments_propositional
+                        .is_literal_root_assignment(reason_literal)
+                {
+                    continue;
+                }
+
+                if self
+                    .sat_data_structures
+                    .assignments_propositional
+                    .is_literal_propagated(reason_literal)
+                    && self.get_abstract_level(reason_literal) & abstract_levels != 0
+                {
+                    self.seen[reason_literal.get_propositional_variable()] = true;
+                    stack.push(reason_literal);
+                    literals_to_clear.push(reason_literal);
+                } else {
+                    for literal in &literals_to_clear[num_literals_to_clear..] {
+                        self.seen[literal.get_propositional_variable()] = false;
+                    }
+                    literals_to_clear.truncate(num_literals_to_clear);
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn get_abstract_level(&self, literal: Literal) -> u32 {
+        1 << (self
+            .sat_data_structures
+            .assignments_propositional
+            .get_literal_assignment_level(literal)
+            % 32)
+    }
+
     fn propagate_enqueued(&mut self) {
         let num_assigned_variables_old = self
             .sat_data_structures
@@ -650,7 +977,6 @@ impl ConstraintSatisfactionSolver {
         }
 
         self.counters.num_conflicts += self.state.conflict_detected() as u64;
-        self.counters.num_conflicts_until_restart -= self.state.conflict_detected() as i64;
 
         self.counters.num_propagations +=
             self.sat_data_structures
@@ -766,8 +1092,6 @@ impl ConstraintSatisfactionSolver {
             .watch_list_cp
             .add_watches_for_propagator(new_propagator.as_ref(), new_propagator_id);
 
-        new_propagator.initialise_at_root(&mut domains);
-
         let root_status = new_propagator.initialise_at_root(&mut domains);
 
         pumpkin_assert_simple!(root_status.no_conflict(), "For now we crash when adding a new propagator that detects a conflict at the root node, even though this is not necessarily an error. Should handle better in the future.");
@@ -856,19 +1180,23 @@ struct Counters {
     pub num_conflicts: u64,
     pub num_propagations: u64,
     pub num_unit_clauses_learned: u64,
-    pub num_conflicts_until_restart: i64, //in case the solver gets into a chain of conflicts, this value could go get negative
     pub num_restarts: u64,
+    pub num_conflicts_at_last_inprocessing: u64,
+    pub num_conflicts_at_call_start: u64,
+    pub num_propagations_at_call_start: u64,
 }
 
 impl Counters {
-    fn new(num_conflicts_until_restart: i64) -> Counters {
+    fn new() -> Counters {
         Counters {
             num_decisions: 0,
             num_conflicts: 0,
             num_propagations: 0,
             num_unit_clauses_learned: 0,
-            num_conflicts_until_restart,
             num_restarts: 0,
+            num_conflicts_at_last_inprocessing: 0,
+            num_conflicts_at_call_start: 0,
+            num_propagations_at_call_start: 0,
         }
     }
 }
@@ -987,7 +1315,9 @@ impl CSPSolverState {
     }
 
     fn declare_ready(&mut self) {
-        pumpkin_assert_simple!(self.has_solution());
+        pumpkin_assert_simple!(
+            self.has_solution() || self.is_infeasible_under_assumptions() || self.timeout()
+        );
         self.internal_state = CSPSolverStateInternal::Ready;
     }
 
@@ -1030,8 +1360,13 @@ impl CSPSolverState {
 impl Default for SatisfactionSolverOptions {
     fn default() -> Self {
         SatisfactionSolverOptions {
+            restart_strategy: RestartStrategy::Glucose,
             conflicts_per_restart: 4000,
             certificate_file: None,
+            use_clause_minimisation: true,
+            conflicts_per_inprocessing: Some(20000),
+            conflict_limit: None,
+            propagation_limit: None,
         }
     }
 }
@@ -1044,3 +1379,469 @@ impl Default for ConstraintSatisfactionSolver {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::propagators::TimeTableCumulativePropagator;
+
+    fn create_variables(csp_solver: &mut ConstraintSatisfactionSolver, n: usize) -> Vec<Literal> {
+        csp_solver.new_literals().take(n).collect()
+    }
+
+    //x0 -> x1 -> x2, and x3 -> !x2
+    fn create_implication_chain() -> (ConstraintSatisfactionSolver, Vec<Literal>) {
+        let mut csp_solver = ConstraintSatisfactionSolver::default();
+        let xs = create_variables(&mut csp_solver, 4);
+        for clause in [
+            vec![!xs[0], xs[1]],
+            vec![!xs[1], xs[2]],
+            vec![!xs[3], !xs[2]],
+        ] {
+            assert_eq!(
+                ClauseAdditionOutcome::NoConflictDetected,
+                csp_solver.add_permanent_clause(clause)
+            );
+        }
+        (csp_solver, xs)
+    }
+
+    #[test]
+    fn test_feasible_under_assumptions() {
+        let (mut csp_solver, xs) = create_implication_chain();
+
+        let flag = csp_solver.solve_under_assumptions(&[xs[0], !xs[3]], i64::MAX);
+
+        assert!(matches!(flag, CSPSolverExecutionFlag::Feasible));
+        let assignments = csp_solver.get_propositional_assignments();
+        assert!(assignments.is_literal_assigned_true(xs[0]));
+        assert!(assignments.is_literal_assigned_true(xs[2]));
+        assert!(assignments.is_literal_assigned_false(xs[3]));
+    }
+
+    #[test]
+    fn test_core_only_contains_responsible_assumptions() {
+        let (mut csp_solver, xs) = create_implication_chain();
+        let unrelated = create_variables(&mut csp_solver, 1)[0];
+
+        let flag = csp_solver.solve_under_assumptions(&[unrelated, xs[0], xs[3]], i64::MAX);
+
+        assert!(matches!(
+            flag,
+            CSPSolverExecutionFlag::InfeasibleUnderAssumptions
+        ));
+        let mut core = csp_solver.extract_core();
+        core.sort_by_key(|literal| literal.to_u32());
+        assert!(core == vec![xs[0], xs[3]]);
+    }
+
+    #[test]
+    fn test_contradicting_assumptions() {
+        let (mut csp_solver, xs) = create_implication_chain();
+
+        let flag = csp_solver.solve_under_assumptions(&[xs[1], !xs[1]], i64::MAX);
+
+        assert!(matches!(
+            flag,
+            CSPSolverExecutionFlag::InfeasibleUnderAssumptions
+        ));
+        let mut core = csp_solver.extract_core();
+        core.sort_by_key(|literal| literal.to_u32());
+        assert!(core == vec![!xs[1], xs[1]]);
+    }
+
+    #[test]
+    fn test_assumption_falsified_at_the_root() {
+        let (mut csp_solver, xs) = create_implication_chain();
+        assert_eq!(
+            ClauseAdditionOutcome::NoConflictDetected,
+            csp_solver.add_unit_clause(xs[3])
+        );
+
+        let flag = csp_solver.solve_under_assumptions(&[xs[1], xs[0]], i64::MAX);
+
+        assert!(matches!(
+            flag,
+            CSPSolverExecutionFlag::InfeasibleUnderAssumptions
+        ));
+        assert!(csp_solver.extract_core() == vec![xs[1]]);
+    }
+
+    #[test]
+    fn test_incremental_calls_without_restoring() {
+        let (mut csp_solver, xs) = create_implication_chain();
+
+        assert!(matches!(
+            csp_solver.solve_under_assumptions(&[xs[0], xs[3]], i64::MAX),
+            CSPSolverExecutionFlag::InfeasibleUnderAssumptions
+        ));
+        assert!(matches!(
+            csp_solver.solve_under_assumptions(&[xs[3]], i64::MAX),
+            CSPSolverExecutionFlag::Feasible
+        ));
+        assert!(csp_solver
+            .get_propositional_assignments()
+            .is_literal_assigned_false(xs[0]));
+        assert!(matches!(
+            csp_solver.solve_under_assumptions(&[xs[0]], i64::MAX),
+            CSPSolverExecutionFlag::Feasible
+        ));
+        assert!(matches!(
+            csp_solver.solve(i64::MAX),
+            CSPSolverExecutionFlag::Feasible
+        ));
+    }
+
+    //same clauses as for clause minimisation: under a and b, the solver has to learn (!a \/ !b) through a conflict
+    #[test]
+    fn test_learned_clause_is_kept_for_the_next_call() {
+        let mut csp_solver = ConstraintSatisfactionSolver::default();
+        let xs = create_variables(&mut csp_solver, 4);
+        let (a, b, c, d) = (xs[0], xs[1], xs[2], xs[3]);
+        for clause in [vec![!a, c], vec![!a, !b, d], vec![!b, !c, !d]] {
+            assert_eq!(
+                ClauseAdditionOutcome::NoConflictDetected,
+                csp_solver.add_permanent_clause(clause)
+            );
+        }
+
+        assert!(matches!(
+            csp_solver.solve_under_assumptions(&[a, b], i64::MAX),
+            CSPSolverExecutionFlag::InfeasibleUnderAssumptions
+        ));
+        assert!(csp_solver.counters.num_conflicts > 0);
+        let learned_clauses = csp_solver.sat_data_structures.learned_clauses_core.clone();
+        assert_eq!(learned_clauses.len(), 1);
+
+        //the learned clause propagates !b as soon as a is decided, so no conflict is needed this time
+        let num_conflicts = csp_solver.counters.num_conflicts;
+        assert!(matches!(
+            csp_solver.solve_under_assumptions(&[a, b], i64::MAX),
+            CSPSolverExecutionFlag::InfeasibleUnderAssumptions
+        ));
+        assert_eq!(csp_solver.counters.num_conflicts, num_conflicts);
+        assert!(csp_solver.sat_data_structures.learned_clauses_core == learned_clauses);
+        assert!(csp_solver.state.get_violated_assumption() == b);
+    }
+
+    #[test]
+    fn test_termination_flag_stops_the_solver() {
+        let (mut csp_solver, _) = create_implication_chain();
+        let termination_flag = Arc::new(AtomicBool::new(true));
+        csp_solver.set_termination_flag(Arc::clone(&termination_flag));
+
+        assert!(matches!(
+            csp_solver.solve(i64::MAX),
+            CSPSolverExecutionFlag::Timeout
+        ));
+    }
+
+    //x0 and x1 cannot take any value without a conflict, so finding that requires at least one conflict
+    #[test]
+    fn test_conflict_limit_stops_the_solver() {
+        let mut csp_solver = ConstraintSatisfactionSolver::new(
+            SATDataStructuresInternalParameters::default(),
+            SatisfactionSolverOptions {
+                conflict_limit: Some(1),
+                ..Default::default()
+            },
+        );
+        let xs = create_variables(&mut csp_solver, 2);
+        for clause in [
+            vec![xs[0], xs[1]],
+            vec![xs[0], !xs[1]],
+            vec![!xs[0], xs[1]],
+            vec![!xs[0], !xs[1]],
+        ] {
+            let _ = csp_solver.add_permanent_clause(clause);
+        }
+
+        assert!(matches!(
+            csp_solver.solve(i64::MAX),
+            CSPSolverExecutionFlag::Timeout
+        ));
+    }
+
+    //the per-call limit is counted from the start of each call, so a second call can make progress again
+    #[test]
+    fn test_call_conflict_limit_is_counted_per_call() {
+        let mut csp_solver = ConstraintSatisfactionSolver::default();
+        let xs = create_variables(&mut csp_solver, 3);
+        for clause in [
+            vec![xs[0], xs[1]],
+            vec![xs[0], !xs[1]],
+            vec![!xs[0], xs[2]],
+            vec![!xs[0], !xs[2]],
+        ] {
+            let _ = csp_solver.add_permanent_clause(clause);
+        }
+        let call_limits = CallLimits {
+            conflicts: Some(1),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            csp_solver.solve_with_limits(i64::MAX, call_limits),
+            CSPSolverExecutionFlag::Timeout
+        ));
+        assert_eq!(csp_solver.counters.num_conflicts, 1);
+        //the unit clause learned in the first call is kept, so one more conflict proves infeasibility
+        assert!(matches!(
+            csp_solver.solve_with_limits(i64::MAX, call_limits),
+            CSPSolverExecutionFlag::Infeasible
+        ));
+    }
+
+    #[test]
+    fn test_learned_clause_tiers() {
+        let mut csp_solver = ConstraintSatisfactionSolver::new(
+            SATDataStructuresInternalParameters {
+                num_learned_clauses_max: 2,
+                lbd_threshold_core: 2,
+                lbd_threshold_tier2: 3,
+                ..Default::default()
+            },
+            SatisfactionSolverOptions::default(),
+        );
+        let xs = create_variables(&mut csp_solver, 4);
+        let sat_data_structures = &mut csp_solver.sat_data_structures;
+
+        let core = sat_data_structures.add_learned_clause_unchecked(vec![xs[0], xs[1]], 2);
+        let used_tier2 =
+            sat_data_structures.add_learned_clause_unchecked(vec![xs[0], xs[1], xs[2]], 3);
+        let unused_tier2 =
+            sat_data_structures.add_learned_clause_unchecked(vec![xs[1], xs[2], xs[3]], 3);
+        let active_local = sat_data_structures.add_learned_clause_unchecked(xs.clone(), 4);
+        let inactive_locals: Vec<ClauseReference> = (0..2)
+            .map(|_| sat_data_structures.add_learned_clause_unchecked(xs.clone(), 4))
+            .collect();
+        sat_data_structures.clause_allocator[used_tier2].mark_used();
+        sat_data_structures.bump_clause_activity(unused_tier2);
+        sat_data_structures.bump_clause_activity(active_local);
+        sat_data_structures.bump_clause_activity(active_local);
+
+        sat_data_structures.shrink_learned_clause_database_if_needed();
+
+        //the unused tier2 clause joins the three local clauses, and the two that were never bumped are deleted
+        assert!(sat_data_structures.learned_clauses_core == vec![core]);
+        assert!(sat_data_structures.learned_clauses_tier2 == vec![used_tier2]);
+        assert!(sat_data_structures.learned_clauses_local == vec![active_local, unused_tier2]);
+        for clause_reference in inactive_locals {
+            assert!(sat_data_structures.clause_allocator[clause_reference].is_deleted());
+        }
+        assert!(!sat_data_structures.clause_allocator[used_tier2].is_used());
+    }
+
+    #[test]
+    fn test_inprocessing_subsumes_and_strengthens_clauses() {
+        let mut csp_solver = ConstraintSatisfactionSolver::new(
+            SATDataStructuresInternalParameters::default(),
+            SatisfactionSolverOptions::default(),
+        );
+        let xs = create_variables(&mut csp_solver, 5);
+        for clause in [
+            vec![xs[0], xs[1]],
+            vec![xs[0], xs[1], xs[2]],
+            vec![!xs[0], xs[1], xs[3]],
+            vec![xs[2], !xs[4]],
+        ] {
+            let _ = csp_solver.add_permanent_clause(clause);
+        }
+        let sat_data_structures = &mut csp_solver.sat_data_structures;
+        let _ = sat_data_structures.add_learned_clause_unchecked(vec![xs[2], xs[3], xs[4]], 2);
+
+        sat_data_structures.inprocess();
+
+        //clauses are compared as sorted lists of literal codes, since the order of the literals may change
+        fn to_sorted_codes(clauses: Vec<Vec<Literal>>) -> Vec<Vec<u32>> {
+            let mut codes: Vec<Vec<u32>> = clauses
+                .iter()
+                .map(|clause| {
+                    let mut codes: Vec<u32> =
+                        clause.iter().map(|literal| literal.to_u32()).collect();
+                    codes.sort();
+                    codes
+                })
+                .collect();
+            codes.sort();
+            codes
+        }
+        let get_clauses = |clause_references: &[ClauseReference]| {
+            to_sorted_codes(
+                clause_references
+                    .iter()
+                    .map(|clause_reference| {
+                        sat_data_structures.clause_allocator[*clause_reference]
+                            .get_literal_slice()
+                            .to_vec()
+                    })
+                    .collect(),
+            )
+        };
+
+        //(x0 \/ x1) subsumes (x0 \/ x1 \/ x2) and removes !x0 from (!x0 \/ x1 \/ x3)
+        //  likewise, (x2 \/ !x4) removes x4 from the learned clause
+        assert_eq!(
+            get_clauses(&sat_data_structures.permanent_clauses),
+            to_sorted_codes(vec![
+                vec![xs[0], xs[1]],
+                vec![xs[1], xs[3]],
+                vec![xs[2], !xs[4]]
+            ])
+        );
+        assert_eq!(
+            get_clauses(&sat_data_structures.learned_clauses_core),
+            to_sorted_codes(vec![vec![xs[2], xs[3]]])
+        );
+    }
+
+    //if a is false, then x is false and b is true, so the last literal of (a \/ b \/ c) is not needed
+    //  the tier2 clause (d \/ e \/ f) is shortened in the same way, while local clauses are not vivified
+    #[test]
+    fn test_inprocessing_vivifies_learned_clauses() {
+        let mut csp_solver = ConstraintSatisfactionSolver::default();
+        let xs = create_variables(&mut csp_solver, 8);
+        let (a, b, c, x) = (xs[0], xs[1], xs[2], xs[3]);
+        let (d, e, f, y) = (xs[4], xs[5], xs[6], xs[7]);
+        for clause in [vec![a, !x], vec![x, b], vec![d, !y], vec![y, e]] {
+            assert_eq!(
+                ClauseAdditionOutcome::NoConflictDetected,
+                csp_solver.add_permanent_clause(clause)
+            );
+        }
+        let sat_data_structures = &mut csp_solver.sat_data_structures;
+        let _ = sat_data_structures.add_learned_clause_unchecked(vec![a, b, c], 2);
+        let _ = sat_data_structures.add_learned_clause_unchecked(vec![d, e, f], 4);
+        let local = sat_data_structures.add_learned_clause_unchecked(vec![a, b, f], 10);
+
+        sat_data_structures.inprocess();
+
+        let get_literals = |clause_references: &[ClauseReference]| -> Vec<Vec<Literal>> {
+            clause_references
+                .iter()
+                .map(|clause_reference| {
+                    sat_data_structures.clause_allocator[*clause_reference]
+                        .get_literal_slice()
+                        .to_vec()
+                })
+                .collect()
+        };
+        assert!(get_literals(&sat_data_structures.learned_clauses_core) == vec![vec![a, b]]);
+        assert!(get_literals(&sat_data_structures.learned_clauses_tier2) == vec![vec![d, e]]);
+        assert!(sat_data_structures.learned_clauses_local == vec![local]);
+        assert!(!sat_data_structures.clause_allocator[local].is_deleted());
+    }
+
+    //x0 is set at the root, which satisfies (x0 \/ a) and makes the time-table propagator set x1 to zero
+    #[test]
+    fn test_inprocessing_removes_root_satisfied_clauses_in_the_certificate() {
+        let certificate_path = std::env::temp_dir().join(format!(
+            "pumpkin_inprocessing_certificate_{}.drat",
+            std::process::id()
+        ));
+        let mut csp_solver = ConstraintSatisfactionSolver::new(
+            SATDataStructuresInternalParameters::default(),
+            SatisfactionSolverOptions {
+                certificate_file: Some(File::create(&certificate_path).unwrap()),
+                ..Default::default()
+            },
+        );
+        let integer_variables: Vec<IntegerVariable> = (0..2)
+            .map(|_| csp_solver.create_new_integer_variable(0, 1))
+            .collect();
+        let mut propagator = TimeTableCumulativePropagator::new();
+        propagator.add_time_point(&integer_variables, &[2, 2], 3);
+        csp_solver.add_propagator(Box::new(propagator));
+        let xs: Vec<Literal> = integer_variables
+            .iter()
+            .map(|x| csp_solver.get_lower_bound_literal(*x, 1))
+            .collect();
+        let ys = create_variables(&mut csp_solver, 2);
+        for clause in [vec![xs[0], ys[0]], vec![ys[0], ys[1]]] {
+            assert_eq!(
+                ClauseAdditionOutcome::NoConflictDetected,
+                csp_solver.add_permanent_clause(clause)
+            );
+        }
+        assert_eq!(
+            ClauseAdditionOutcome::NoConflictDetected,
+            csp_solver.add_unit_clause(xs[0])
+        );
+        assert!(csp_solver
+            .get_propositional_assignments()
+            .is_literal_assigned_false(xs[1]));
+
+        //the second round has nothing left to simplify, and should not log the units again
+        csp_solver.sat_data_structures.inprocess();
+        csp_solver.sat_data_structures.inprocess();
+
+        let sat_data_structures = &csp_solver.sat_data_structures;
+        assert_eq!(sat_data_structures.permanent_clauses.len(), 1);
+
+        let certificate = std::fs::read_to_string(&certificate_path).unwrap();
+        std::fs::remove_file(&certificate_path).unwrap();
+        let to_dimacs = |literal: Literal| {
+            let index = literal.get_propositional_variable().index() as i64;
+            if literal.is_negative() {
+                -index
+            } else {
+                index
+            }
+        };
+        let (x0, x1, y0) = (to_dimacs(xs[0]), to_dimacs(xs[1]), to_dimacs(ys[0]));
+        let lines: Vec<&str> = certificate.lines().collect();
+        //the unit x0 is given, while !x1 is only implied by the propagator, so it is left out
+        //  the deletion of the satisfied clause is logged, with its literals in either order
+        let unit = format!("{} 0", x0);
+        assert_eq!(lines.iter().filter(|line| **line == unit).count(), 1);
+        assert!(!lines.contains(&format!("{} 0", -x1).as_str()));
+        assert!(
+            lines.contains(&format!("d {} {} 0", x0, y0).as_str())
+                || lines.contains(&format!("d {} {} 0", y0, x0).as_str())
+        );
+    }
+
+    //a -> c, and under a and b, d is propagated which conflicts with c
+    //  the 1UIP clause is (!a \/ !b \/ !c), where !c is implied by !a
+    fn learn_clause_with_redundant_literal(use_clause_minimisation: bool) -> Vec<Literal> {
+        let mut csp_solver = ConstraintSatisfactionSolver::new(
+            SATDataStructuresInternalParameters::default(),
+            SatisfactionSolverOptions {
+                use_clause_minimisation,
+                ..Default::default()
+            },
+        );
+        let xs = create_variables(&mut csp_solver, 4);
+        let (a, b, c, d) = (xs[0], xs[1], xs[2], xs[3]);
+        for clause in [vec![!a, c], vec![!a, !b, d], vec![!b, !c, !d]] {
+            assert_eq!(
+                ClauseAdditionOutcome::NoConflictDetected,
+                csp_solver.add_permanent_clause(clause)
+            );
+        }
+
+        assert!(matches!(
+            csp_solver.solve_under_assumptions(&[a, b], i64::MAX),
+            CSPSolverExecutionFlag::InfeasibleUnderAssumptions
+        ));
+
+        let sat_data_structures = &csp_solver.sat_data_structures;
+        assert_eq!(sat_data_structures.learned_clauses_core.len(), 1);
+        let mut learned_clause = sat_data_structures.clause_allocator
+            [sat_data_structures.learned_clauses_core[0]]
+            .get_literal_slice()
+            .to_vec();
+        learned_clause.sort_by_key(|literal| literal.to_u32());
+        learned_clause
+    }
+
+    #[test]
+    fn test_clause_minimisation_removes_implied_literals() {
+        let xs: Vec<Literal> = ConstraintSatisfactionSolver::default()
+            .new_literals()
+            .take(3)
+            .collect();
+        assert!(learn_clause_with_redundant_literal(true) == vec![!xs[0], !xs[1]]);
+        assert!(learn_clause_with_redundant_literal(false) == vec![!xs[0], !xs[1], !xs[2]]);
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use crate::{
+    basic_types::{
+        CSPSolverExecutionFlag, ClauseAdditionOutcome, Function, Literal, Solution,
+        SolutionTracker, Stopwatch,
+    },
+    pumpkin_asserts::{pumpkin_assert_moderate, pumpkin_assert_simple},
+};
+use log::info;
+
+use super::ConstraintSatisfactionSolver;
+
+//core-guided optimisation using the OLL algorithm
+//  the solver is asked to satisfy every objective literal under assumptions, and each core that is found raises the lower bound
+//  the literals of the core are then replaced in the objective by totaliser outputs that count how many of them are violated
+//implementation is done based on the paper:
+//"Unsatisfiability-based optimization in clasp.", Andres Benjamin, Benjamin Kaufmann, Oliver Matheis, and Torsten Schaub. ICLP'12.
+pub struct CoreGuidedSearch {}
+
+impl CoreGuidedSearch {
+    pub fn new() -> CoreGuidedSearch {
+        CoreGuidedSearch {}
+    }
+
+    pub fn solve(
+        &self,
+        csp_solver: &mut ConstraintSatisfactionSolver,
+        objective_function: &Function,
+        stopwatch: &Stopwatch,
+        solution_tracker: &mut SolutionTracker,
+    ) {
+        pumpkin_assert_simple!(
+            csp_solver.get_state().has_solution() && solution_tracker.has_solution(),
+            "Core-guided search assumes the solver contains a feasible solution."
+        );
+
+        //the objective as it is reformulated after each core, the weight of a literal is the cost of setting it to true
+        let mut reformulated_objective: HashMap<Literal, u64> = objective_function
+            .get_function_as_weighted_literals_vector(csp_solver)
+            .iter()
+            .map(|wl| (wl.literal, wl.weight))
+            .collect();
+        let mut lower_bound = objective_function.get_constant_term();
+
+        csp_solver.restore_state_at_root();
+
+        loop {
+            if solution_tracker.has_optimal_solution() {
+                return;
+            }
+
+            //sorted to avoid the randomness of the hashmap, see 'get_function_as_weighted_literals_vector'
+            let mut assumptions: Vec<Literal> = reformulated_objective
+                .iter()
+                .filter(|(_, weight)| **weight > 0)
+                .map(|(literal, _)| !*literal)
+                .collect();
+            assumptions.sort_by_key(|literal| literal.to_u32());
+
+            let csp_execution_flag = csp_solver.solve_under_assumptions(
+                &assumptions,
+                stopwatch.get_remaining_time_budget_in_milliseconds(),
+            );
+
+            match csp_execution_flag {
+                CSPSolverExecutionFlag::Feasible => {
+                    //no objective literal is violated in the reformulated objective, so the cost of the solution is the lower bound
+                    pumpkin_assert_moderate!(
+                        objective_function.evaluate_assignment(
+                            csp_solver.get_propositional_assignments(),
+                            csp_solver.get_integer_assignments(),
+                        ) == lower_bound,
+                        "A solution satisfying all assumptions must meet the lower bound."
+                    );
+                    solution_tracker.update_solution(&Solution::new(
+                        csp_solver.get_propositional_assignments(),
+                        csp_solver.get_integer_assignments(),
+                    ));
+                    solution_tracker.declare_optimal();
+                    return;
+                }
+                CSPSolverExecutionFlag::InfeasibleUnderAssumptions => {
+                    let core: Vec<Literal> = csp_solver
+                        .extract_core()
+                        .into_iter()
+                        .map(|assumption| !assumption)
+                        .collect();
+                    csp_solver.restore_state_at_root();
+                    let core_weight = core
+                        .iter()
+                        .map(|literal| reformulated_objective[literal])
+                        .min()
+                        .unwrap();
+
+                    lower_bound += core_weight;
+                    info!(
+                        "Core of size {} raised the lower bound to {} after {} seconds",
+                        core.len(),
+                        lower_bound,
+                        stopwatch.get_elapsed_time()
+                    );
+                    solution_tracker.update_lower_bound(lower_bound);
+
+                    for literal in core.iter() {
+                        *reformulated_objective.get_mut(literal).unwrap() -= core_weight;
+                    }
+
+                    //at least one literal of the core is violated, which is now part of the lower bound
+                    //  violating more than one costs the weight of the core for each additional literal
+                    if core.len() > 1 {
+                        let outputs = CoreGuidedSearch::encode_totaliser(&core, csp_solver);
+                        for output in outputs.into_iter().skip(1) {
+                            *reformulated_objective.entry(output).or_insert(0) += core_weight;
+                        }
+                    }
+                }
+                //the totaliser clauses cannot make the problem infeasible, so this can only happen if the hard clauses are infeasible
+                CSPSolverExecutionFlag::Infeasible => {
+                    return;
+                }
+                CSPSolverExecutionFlag::Timeout => {
+                    return;
+                }
+            }
+        }
+    }
+
+    //returns literals o_1, ..., o_n, where o_k is set to true if at least k of the input literals are true
+    //  only this direction is needed, since the outputs are only ever penalised in the objective
+    fn encode_totaliser(
+        literals: &[Literal],
+        csp_solver: &mut ConstraintSatisfactionSolver,
+    ) -> Vec<Literal> {
+        if literals.len() == 1 {
+            return literals.to_vec();
+        }
+
+        let (left, right) = literals.split_at(literals.len() / 2);
+        let left = CoreGuidedSearch::encode_totaliser(left, csp_solver);
+        let right = CoreGuidedSearch::encode_totaliser(right, csp_solver);
+        let outputs: Vec<Literal> = csp_solver.new_literals().take(literals.len()).collect();
+
+        let mut add_clause = |clause: Vec<Literal>| {
+            let outcome = csp_solver.add_permanent_clause(clause);
+            pumpkin_assert_simple!(
+                outcome == ClauseAdditionOutcome::NoConflictDetected,
+                "Totaliser clauses define new literals, so they cannot lead to a conflict."
+            );
+        };
+        for (i, left_literal) in left.iter().enumerate() {
+            add_clause(vec![!*left_literal, outputs[i]]);
+        }
+        for (j, right_literal) in right.iter().enumerate() {
+            add_clause(vec![!*right_literal, outputs[j]]);
+        }
+        for (i, left_literal) in left.iter().enumerate() {
+            for (j, right_literal) in right.iter().enumerate() {
+                add_clause(vec![!*left_literal, !*right_literal, outputs[i + j + 1]]);
+            }
+        }
+        outputs
+    }
+}
@@ -1,11 +1,10 @@
 use crate::{
-    basic_types::{CSPSolverExecutionFlag, Function, Solution, SolutionValuePair, Stopwatch},
+    basic_types::{CSPSolverExecutionFlag, Function, Solution, SolutionTracker, Stopwatch},
     encoders::{
         CardinalityNetworkEncoder, EncodingStatus, GeneralisedTotaliserEncoder, UpperBoundEncoder,
     },
     pumpkin_asserts::{pumpkin_assert_moderate, pumpkin_assert_simple},
 };
-use log::info;
 
 use super::ConstraintSatisfactionSolver;
 
@@ -20,6 +19,25 @@ pub enum UpperBoundEncoding {
     CNE,
 }
 
+impl UpperBoundEncoding {
+    pub fn create_encoder(
+        self,
+        objective_function: &Function,
+        csp_solver: &mut ConstraintSatisfactionSolver,
+    ) -> Box<dyn UpperBoundEncoder> {
+        match self {
+            UpperBoundEncoding::GTE => Box::new(GeneralisedTotaliserEncoder::new(
+                objective_function,
+                csp_solver,
+            )),
+            UpperBoundEncoding::CNE => Box::new(CardinalityNetworkEncoder::from_function(
+                objective_function,
+                csp_solver,
+            )),
+        }
+    }
+}
+
 impl LinearSearch {
     pub fn new(upper_bound_encoding: UpperBoundEncoding) -> LinearSearch {
         LinearSearch {
@@ -32,45 +50,24 @@ impl LinearSearch {
         csp_solver: &mut ConstraintSatisfactionSolver,
         objective_function: &Function,
         stopwatch: &Stopwatch,
-    ) -> SolutionValuePair {
+        solution_tracker: &mut SolutionTracker,
+    ) {
         pumpkin_assert_simple!(
-            csp_solver.get_state().has_solution(),
+            csp_solver.get_state().has_solution() && solution_tracker.has_solution(),
             "Linear search assumes the solver contains a feasible solution."
         );
 
-        let mut best_solution = Solution::new(
-            csp_solver.get_propositional_assignments(),
-            csp_solver.get_integer_assignments(),
-        );
-
-        let mut best_objective_value = objective_function.evaluate_assignment(
-            csp_solver.get_propositional_assignments(),
-            csp_solver.get_integer_assignments(),
-        );
-
-        println!("o {}", best_objective_value);
-        info!(
-            "Current objective is {} after {} seconds",
-            best_objective_value,
-            stopwatch.get_elapsed_time()
-        );
-
-        let mut upper_bound_encoder: Box<dyn UpperBoundEncoder> = match self.upper_bound_encoding {
-            UpperBoundEncoding::GTE => Box::new(GeneralisedTotaliserEncoder::new(
-                objective_function,
-                csp_solver,
-            )),
-            UpperBoundEncoding::CNE => Box::new(CardinalityNetworkEncoder::from_function(
-                objective_function,
-                csp_solver,
-            )),
-        };
+        let mut upper_bound_encoder = self
+            .upper_bound_encoding
+            .create_encoder(objective_function, csp_solver);
 
         loop {
-            if best_objective_value == objective_function.get_constant_term() {
-                return SolutionValuePair::new(best_solution, best_objective_value);
+            if solution_tracker.has_optimal_solution() {
+                return;
             }
 
+            let best_objective_value = solution_tracker.get_best_objective_value();
+
             csp_solver.set_solution_guided_search();
 
             csp_solver.restore_state_at_root();
@@ -81,10 +78,12 @@ impl LinearSearch {
             //in case some cases infeasibility can be detected while constraining the upper bound
             //  meaning the current best solution is optimal
             if let EncodingStatus::ConflictDetected = encoding_status {
-                return SolutionValuePair::new(best_solution, best_objective_value);
+                solution_tracker.declare_optimal();
+                return;
             }
 
-            let csp_execution_flag = csp_solver.solve(stopwatch.get_remaining_time_budget());
+            let csp_execution_flag =
+                csp_solver.solve(stopwatch.get_remaining_time_budget_in_milliseconds());
 
             match csp_execution_flag {
                 CSPSolverExecutionFlag::Feasible => {
@@ -98,25 +97,19 @@ impl LinearSearch {
 
                     //need to include a simple refinement step here, since it could be that the returned solution can be trivially improved
 
-                    best_objective_value = objective_function.evaluate_assignment(
-                        csp_solver.get_propositional_assignments(),
-                        csp_solver.get_integer_assignments(),
-                    );
-                    best_solution.update(
+                    solution_tracker.update_solution(&Solution::new(
                         csp_solver.get_propositional_assignments(),
                         csp_solver.get_integer_assignments(),
-                    );
-
-                    println!("o {}", best_objective_value);
-                    info!("Current objective is {} after {} seconds", best_objective_value, stopwatch.get_elapsed_time());
+                    ));
                 }
                 CSPSolverExecutionFlag::Infeasible => {
-                    return SolutionValuePair::new(best_solution, best_objective_value);
+                    solution_tracker.declare_optimal();
+                    return;
                 }
                 CSPSolverExecutionFlag::Timeout => {
-                    return SolutionValuePair::new(best_solution, best_objective_value);
+                    return;
                 }
-                CSPSolverExecutionFlag::InfeasibleUnderAssumptions => panic!("Do not expect to be infeasible under assumption as a result of a linear search call.")               
+                CSPSolverExecutionFlag::InfeasibleUnderAssumptions => panic!("Do not expect to be infeasible under assumption as a result of a linear search call.")
             }
         }
     }
@@ -1,15 +1,23 @@
+mod binary_search;
 mod constraint_satisfaction_solver;
+mod core_guided_search;
 mod cp;
 mod debug_helper;
 mod linear_search;
 mod pumpkin;
+mod restart_strategy;
 mod sat;
 mod sat_cp_mediator;
 
-pub use constraint_satisfaction_solver::{ConstraintSatisfactionSolver, SatisfactionSolverOptions};
+pub use binary_search::{BinarySearch, BoundSelection};
+pub use constraint_satisfaction_solver::{
+    CallLimits, ConstraintSatisfactionSolver, SatisfactionSolverOptions,
+};
+pub use core_guided_search::CoreGuidedSearch;
 pub use cp::*;
 pub use debug_helper::DebugHelper;
 pub use linear_search::{LinearSearch, UpperBoundEncoding};
-pub use pumpkin::Pumpkin;
+pub use pumpkin::{OptimisationStrategy, Pumpkin};
+pub use restart_strategy::RestartStrategy;
 pub use sat::*;
 pub use sat_cp_mediator::SATCPMediator;
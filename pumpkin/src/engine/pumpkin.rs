@@ -1,80 +1,110 @@
 use log::debug;
 use std::{
     fs,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     time::{Duration, Instant},
 };
 
 use crate::{
     basic_types::{
         CSPSolverExecutionFlag, FileFormat, Function, IntegerVariable, Literal,
-        PumpkinExecutionFlag, Stopwatch,
+        PropositionalVariable, PumpkinExecutionFlag, Solution, SolutionTracker, Stopwatch,
     },
-    engine::ConstraintSatisfactionSolver,
+    engine::{CallLimits, ConstraintSatisfactionSolver},
     parsers::dimacs::{parse_cnf, parse_wcnf},
     propagators::SimpleLinearInequalityPropagator,
     pumpkin_asserts::pumpkin_assert_simple,
+    result::{PumpkinError, PumpkinResult},
 };
 
 use super::{
-    linear_search::UpperBoundEncoding, LinearSearch, SATDataStructuresInternalParameters,
-    SatisfactionSolverOptions,
+    linear_search::UpperBoundEncoding, BinarySearch, BoundSelection, CoreGuidedSearch,
+    LinearSearch, SATDataStructuresInternalParameters, SatisfactionSolverOptions,
+    VariableOrderingHeuristic,
 };
 
 pub struct Pumpkin {
     csp_solver: ConstraintSatisfactionSolver,
+    optimisation_strategy: OptimisationStrategy,
     linear_search: LinearSearch,
+    binary_search: BinarySearch,
+    progression_search: BinarySearch,
+    core_guided_search: CoreGuidedSearch,
     objective_function: Function,
     stopwatch: Stopwatch,
+    //whether the solver already holds the solution set by set_initial_solution, so solve starts from it
+    has_initial_solution: bool,
+    //kept to tell an interruption apart from the other reasons the solver stops early
+    termination_flag: Arc<AtomicBool>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptimisationStrategy {
+    /// Improves the upper bound until no better solution exists
+    LinearSearch,
+    /// Bisects the gap between the lower bound and the best solution
+    BinarySearch,
+    /// Raises the queried bound geometrically from the lower bound
+    ProgressionSearch,
+    /// Raises the lower bound with unsatisfiable cores until a solution meets it
+    CoreGuided,
 }
 
 impl Pumpkin {
     pub fn new(
         sat_options: SATDataStructuresInternalParameters,
         solver_options: SatisfactionSolverOptions,
+        optimisation_strategy: OptimisationStrategy,
         upper_bound_encoding: UpperBoundEncoding,
         time_limit: Option<Duration>,
     ) -> Pumpkin {
         Pumpkin {
             csp_solver: ConstraintSatisfactionSolver::new(sat_options, solver_options),
+            optimisation_strategy,
             objective_function: Function::new(),
             stopwatch: Stopwatch::new(
                 time_limit
-                    .map(|duration| duration.as_secs() as i64)
+                    .map(|duration| duration.as_millis() as i64)
                     .unwrap_or(i64::MAX),
             ),
             linear_search: LinearSearch::new(upper_bound_encoding),
+            binary_search: BinarySearch::new(upper_bound_encoding, BoundSelection::Bisection),
+            progression_search: BinarySearch::new(
+                upper_bound_encoding,
+                BoundSelection::Progression,
+            ),
+            core_guided_search: CoreGuidedSearch::new(),
+            has_initial_solution: false,
+            termination_flag: Arc::new(AtomicBool::new(false)),
         }
     }
 
     pub fn solve(&mut self) -> PumpkinExecutionFlag {
-        pumpkin_assert_simple!(self.csp_solver.get_state().is_ready());
+        pumpkin_assert_simple!(self.has_initial_solution || self.csp_solver.get_state().is_ready());
 
         debug!(
             "Basic initialisation took {} seconds.",
             self.stopwatch.get_elapsed_time()
         );
 
-        //set phasing saving to an optimistic version, where objective literals are being set to zero
-        let optimistic_phases: Vec<Literal> = self
-            .objective_function
-            .get_function_as_weighted_literals_vector(&self.csp_solver)
-            .iter()
-            .map(|wl| !wl.literal)
-            .collect();
-        self.csp_solver
-            .set_fixed_phases_for_variables(&optimistic_phases);
-
         //compute initial solution
-        let csp_execution_flag = self
-            .csp_solver
-            .solve(self.stopwatch.get_remaining_time_budget());
-
-        if let CSPSolverExecutionFlag::Infeasible = csp_execution_flag {
-            return PumpkinExecutionFlag::Infeasible;
-        }
+        //  unless set_initial_solution has found one already, which the solver still holds
+        if !self.has_initial_solution {
+            self.set_optimistic_phases();
+            let csp_execution_flag = self
+                .csp_solver
+                .solve(self.stopwatch.get_remaining_time_budget_in_milliseconds());
+
+            if let CSPSolverExecutionFlag::Infeasible = csp_execution_flag {
+                return PumpkinExecutionFlag::Infeasible;
+            }
 
-        if let CSPSolverExecutionFlag::Timeout = csp_execution_flag {
-            return PumpkinExecutionFlag::Timeout;
+            if let CSPSolverExecutionFlag::Timeout = csp_execution_flag {
+                return PumpkinExecutionFlag::Timeout;
+            }
         }
 
         debug!(
@@ -82,29 +112,155 @@ impl Pumpkin {
             self.stopwatch.get_elapsed_time()
         );
 
+        let mut solution_tracker = self.track_current_solution();
+
         //simple preprocessing on the objective function
 
-        let output = self.linear_search.solve(
-            &mut self.csp_solver,
-            &self.objective_function,
-            &self.stopwatch,
+        match self.optimisation_strategy {
+            OptimisationStrategy::LinearSearch => self.linear_search.solve(
+                &mut self.csp_solver,
+                &self.objective_function,
+                &self.stopwatch,
+                &mut solution_tracker,
+            ),
+            OptimisationStrategy::BinarySearch => self.binary_search.solve(
+                &mut self.csp_solver,
+                &self.objective_function,
+                &self.stopwatch,
+                &mut solution_tracker,
+            ),
+            OptimisationStrategy::ProgressionSearch => self.progression_search.solve(
+                &mut self.csp_solver,
+                &self.objective_function,
+                &self.stopwatch,
+                &mut solution_tracker,
+            ),
+            OptimisationStrategy::CoreGuided => self.core_guided_search.solve(
+                &mut self.csp_solver,
+                &self.objective_function,
+                &self.stopwatch,
+                &mut solution_tracker,
+            ),
+        };
+
+        let improvements = solution_tracker.get_improvements();
+        debug!(
+            "Found {} improving solutions, the last one after {} seconds.",
+            improvements.len(),
+            improvements.last().unwrap().time.as_secs()
         );
 
-        if self.csp_solver.get_state().timeout() {
-            PumpkinExecutionFlag::Feasible {
-                feasible_solution: output.solution,
-                objective_value: output.objective_value,
+        let objective_value = solution_tracker.get_best_objective_value();
+        let solution = solution_tracker.get_best_solution().unwrap().clone();
+        if solution_tracker.has_optimal_solution() {
+            PumpkinExecutionFlag::Optimal {
+                optimal_solution: solution,
+                objective_value,
             }
         } else {
-            PumpkinExecutionFlag::Optimal {
-                optimal_solution: output.solution,
-                objective_value: output.objective_value,
+            PumpkinExecutionFlag::Feasible {
+                feasible_solution: solution,
+                objective_value,
+                lower_bound: solution_tracker.get_lower_bound(),
             }
         }
     }
 
-    pub fn reset_variable_selection(&mut self, random_seed: i64) {
-        self.csp_solver.reset_variable_selection(random_seed);
+    //the solver stops cooperatively once the flag is set, and the best solution found so far is returned
+    pub fn set_termination_flag(&mut self, termination_flag: Arc<AtomicBool>) {
+        self.termination_flag = Arc::clone(&termination_flag);
+        self.csp_solver.set_termination_flag(termination_flag);
+    }
+
+    pub fn reset_variable_selection(&mut self, heuristic: &VariableOrderingHeuristic) {
+        self.csp_solver.reset_variable_selection(heuristic);
+    }
+
+    //the search starts from the given solution instead of looking for one, which then serves as the first upper bound
+    //  every variable of the instance, numbered from 1 to num_variables, must be assigned by the literals or by propagating them
+    //  this allows leaving out variables that follow from the others, such as the auxiliary variables of a clausal encoding
+    //  the solution found is kept for solve, so nothing should be done with the solver in between
+    pub fn set_initial_solution(
+        &mut self,
+        literals: &[Literal],
+        num_variables: u32,
+    ) -> PumpkinResult<()> {
+        //the variables the solver added for the soft clauses should not be set to true unnecessarily
+        self.set_optimistic_phases();
+        //the solver stops at the first conflict, so completing the assignment never turns into a search
+        //  deciding the variables the solver added for the soft clauses cannot lead to a conflict, so a conflict means the literals are infeasible
+        //  or a variable of the instance was decided, in which case the assignment is rejected as incomplete anyway
+        let call_limits = CallLimits {
+            conflicts: Some(1),
+            propagations: None,
+        };
+        match self.csp_solver.solve_under_assumptions_with_limits(
+            literals,
+            self.stopwatch.get_remaining_time_budget_in_milliseconds(),
+            call_limits,
+        ) {
+            CSPSolverExecutionFlag::Feasible => {
+                let mut is_given = vec![false; num_variables as usize + 1];
+                for literal in literals {
+                    if let Some(given) =
+                        is_given.get_mut(literal.get_propositional_variable().index() as usize)
+                    {
+                        *given = true;
+                    }
+                }
+                let assignments = self.csp_solver.get_propositional_assignments();
+                let decided = (1..=num_variables).find(|index| {
+                    let variable = PropositionalVariable::new(*index);
+                    !is_given[*index as usize]
+                        && !assignments.is_variable_propagated(variable)
+                        && !assignments.is_literal_root_assignment(Literal::new(variable, true))
+                });
+                if let Some(index) = decided {
+                    return Err(PumpkinError::IncompleteAssignment(index));
+                }
+                self.has_initial_solution = true;
+                Ok(())
+            }
+            CSPSolverExecutionFlag::Timeout
+                if self.stopwatch.get_remaining_time_budget_in_milliseconds() <= 0
+                    || self.termination_flag.load(Ordering::Relaxed) =>
+            {
+                Err(PumpkinError::InitialSolutionTimeout)
+            }
+            //the only other reason to stop is the conflict limit
+            CSPSolverExecutionFlag::Timeout => Err(PumpkinError::InfeasibleInitialSolution),
+            CSPSolverExecutionFlag::Infeasible
+            | CSPSolverExecutionFlag::InfeasibleUnderAssumptions => {
+                Err(PumpkinError::InfeasibleInitialSolution)
+            }
+        }
+    }
+
+    //the first incumbent is the solution the solver currently holds
+    fn track_current_solution(&self) -> SolutionTracker {
+        let mut solution_tracker = SolutionTracker::new(&self.objective_function);
+        solution_tracker.update_solution(&Solution::new(
+            self.csp_solver.get_propositional_assignments(),
+            self.csp_solver.get_integer_assignments(),
+        ));
+        solution_tracker
+    }
+
+    //set phasing saving to an optimistic version, where objective literals are being set to zero
+    fn set_optimistic_phases(&mut self) {
+        let optimistic_phases: Vec<Literal> = self
+            .objective_function
+            .get_function_as_weighted_literals_vector(&self.csp_solver)
+            .iter()
+            .map(|wl| !wl.literal)
+            .collect();
+        self.csp_solver
+            .set_fixed_phases_for_variables(&optimistic_phases);
+    }
+
+    //the phases of the objective literals are overwritten by the optimistic phases when solving
+    pub fn set_initial_phases(&mut self, literals: &[Literal]) {
+        self.csp_solver.set_initial_phases_for_variables(literals);
     }
 }
 
@@ -217,3 +373,72 @@ impl Pumpkin {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //exactly one of x1 and x2 is true, and x3 must be true if x1 is
+    //  x1 costs 3 and x2 costs 1, so the optimum sets x2
+    fn create_pumpkin() -> (Pumpkin, Vec<Literal>) {
+        let mut pumpkin = Pumpkin::new(
+            SATDataStructuresInternalParameters::default(),
+            SatisfactionSolverOptions::default(),
+            OptimisationStrategy::LinearSearch,
+            UpperBoundEncoding::GTE,
+            None,
+        );
+        let xs: Vec<Literal> = pumpkin.csp_solver.new_literals().take(3).collect();
+        for clause in [
+            vec![xs[0], xs[1]],
+            vec![!xs[0], !xs[1]],
+            vec![!xs[0], xs[2]],
+        ] {
+            let _ = pumpkin.csp_solver.add_permanent_clause(clause);
+        }
+        pumpkin.objective_function.add_weighted_literal(xs[0], 3);
+        pumpkin.objective_function.add_weighted_literal(xs[1], 1);
+        (pumpkin, xs)
+    }
+
+    //x3 is left out, like the auxiliary variables of a clausal encoding, and follows from x1
+    #[test]
+    fn test_feasible_initial_solution_is_the_first_incumbent() {
+        let (mut pumpkin, xs) = create_pumpkin();
+        assert!(pumpkin.set_initial_solution(&[xs[0], !xs[1]], 3).is_ok());
+
+        let solution_tracker = pumpkin.track_current_solution();
+        assert_eq!(solution_tracker.get_best_objective_value(), 3);
+        assert!(pumpkin
+            .csp_solver
+            .get_propositional_assignments()
+            .is_literal_assigned_true(xs[2]));
+
+        assert!(matches!(
+            pumpkin.solve(),
+            PumpkinExecutionFlag::Optimal {
+                objective_value: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_infeasible_initial_solution_is_rejected() {
+        let (mut pumpkin, xs) = create_pumpkin();
+        assert!(matches!(
+            pumpkin.set_initial_solution(&[xs[0], xs[1]], 3),
+            Err(PumpkinError::InfeasibleInitialSolution)
+        ));
+    }
+
+    //x2 follows from !x1, but x3 is only constrained by x1, so it would have to be decided
+    #[test]
+    fn test_incomplete_initial_solution_is_rejected() {
+        let (mut pumpkin, xs) = create_pumpkin();
+        assert!(matches!(
+            pumpkin.set_initial_solution(&[!xs[0]], 3),
+            Err(PumpkinError::IncompleteAssignment(3))
+        ));
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::VecDeque;
+
+use crate::pumpkin_asserts::pumpkin_assert_simple;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RestartStrategy {
+    /// Restarts after a fixed number of conflicts
+    Fixed,
+    /// Restarts after a number of conflicts following the Luby sequence
+    Luby,
+    /// Restarts when recent learned clauses are worse than average, as in Glucose
+    Glucose,
+}
+
+//the parameters as used in Glucose
+const LBD_WINDOW_SIZE: usize = 50;
+const TRAIL_WINDOW_SIZE: usize = 5000;
+const RESTART_MARGIN: f64 = 0.8;
+const BLOCKING_MARGIN: f64 = 1.4;
+const NUM_CONFLICTS_BEFORE_BLOCKING: u64 = 10000;
+
+//decides when the solver should restart
+//  the solver notifies the restarter of every conflict and every restart
+pub struct Restarter {
+    strategy: RestartStrategy,
+    //for the fixed strategy this is the restart interval, for luby the unit that the sequence is multiplied with
+    conflicts_per_restart: u64,
+    num_conflicts: u64,
+    num_conflicts_since_restart: u64,
+    num_restarts: u64,
+    //the sum of the lbds of all learned clauses is used for the long term average
+    lbd_sum: u64,
+    lbd_window: MovingAverage,
+    trail_window: MovingAverage,
+}
+
+impl Restarter {
+    pub fn new(strategy: RestartStrategy, conflicts_per_restart: u64) -> Restarter {
+        Restarter {
+            strategy,
+            conflicts_per_restart,
+            num_conflicts: 0,
+            num_conflicts_since_restart: 0,
+            num_restarts: 0,
+            lbd_sum: 0,
+            lbd_window: MovingAverage::new(LBD_WINDOW_SIZE),
+            trail_window: MovingAverage::new(TRAIL_WINDOW_SIZE),
+        }
+    }
+
+    //lbd is the lbd of the learned clause, num_assigned_literals is the size of the trail when the conflict was detected
+    pub fn notify_conflict(&mut self, lbd: u32, num_assigned_literals: u64) {
+        self.num_conflicts += 1;
+        self.num_conflicts_since_restart += 1;
+
+        if self.strategy != RestartStrategy::Glucose {
+            return;
+        }
+
+        //restart blocking: a trail that is much larger than usual suggests the solver is close to a solution
+        //  the recent lbds are then forgotten, which postpones the next restart
+        self.trail_window.push(num_assigned_literals);
+        if self.num_conflicts > NUM_CONFLICTS_BEFORE_BLOCKING
+            && self.lbd_window.is_full()
+            && num_assigned_literals as f64 > BLOCKING_MARGIN * self.trail_window.get_average()
+        {
+            self.lbd_window.clear();
+        }
+
+        self.lbd_sum += lbd as u64;
+        self.lbd_window.push(lbd as u64);
+    }
+
+    pub fn should_restart(&self) -> bool {
+        match self.strategy {
+            RestartStrategy::Fixed => {
+                self.num_conflicts_since_restart >= self.conflicts_per_restart
+            }
+            RestartStrategy::Luby => {
+                self.num_conflicts_since_restart
+                    >= luby(self.num_restarts + 1) * self.conflicts_per_restart
+            }
+            RestartStrategy::Glucose => {
+                self.lbd_window.is_full()
+                    && self.lbd_window.get_average() * RESTART_MARGIN
+                        > self.lbd_sum as f64 / self.num_conflicts as f64
+            }
+        }
+    }
+
+    pub fn notify_restart(&mut self) {
+        self.num_restarts += 1;
+        self.lbd_window.clear();
+    }
+
+    //the solver may go back to the root for other reasons than restarting, e.g., after learning a unit clause
+    //  this also starts a new interval of conflicts, but does not move along the luby sequence
+    pub fn notify_backtrack_to_root(&mut self) {
+        self.num_conflicts_since_restart = 0;
+    }
+}
+
+//the i-th element of the luby sequence, starting from i = 1: 1, 1, 2, 1, 1, 2, 4, 1, 1, 2, 1, 1, 2, 4, 8, ...
+fn luby(i: u64) -> u64 {
+    pumpkin_assert_simple!(i > 0);
+    //find the smallest complete subsequence of size 2^k - 1 that contains i
+    let mut k = 1;
+    while (1 << k) - 1 < i {
+        k += 1;
+    }
+    //the last element of a complete subsequence is 2^(k-1), the others repeat the preceding subsequence
+    if i == (1 << k) - 1 {
+        1 << (k - 1)
+    } else {
+        luby(i - (1 << (k - 1)) + 1)
+    }
+}
+
+//the average over the last values, with a fixed window size
+struct MovingAverage {
+    window_size: usize,
+    values: VecDeque<u64>,
+    sum: u64,
+}
+
+impl MovingAverage {
+    fn new(window_size: usize) -> MovingAverage {
+        MovingAverage {
+            window_size,
+            values: VecDeque::with_capacity(window_size),
+            sum: 0,
+        }
+    }
+
+    fn push(&mut self, value: u64) {
+        if self.is_full() {
+            self.sum -= self.values.pop_front().unwrap();
+        }
+        self.values.push_back(value);
+        self.sum += value;
+    }
+
+    fn is_full(&self) -> bool {
+        self.values.len() == self.window_size
+    }
+
+    fn get_average(&self) -> f64 {
+        pumpkin_assert_simple!(!self.values.is_empty());
+        self.sum as f64 / self.values.len() as f64
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+        self.sum = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_luby_sequence() {
+        let sequence: Vec<u64> = (1..=15).map(luby).collect();
+        assert_eq!(sequence, vec![1, 1, 2, 1, 1, 2, 4, 1, 1, 2, 1, 1, 2, 4, 8]);
+    }
+
+    #[test]
+    fn test_luby_restarts_follow_the_sequence() {
+        let mut restarter = Restarter::new(RestartStrategy::Luby, 10);
+        let mut intervals = vec![];
+        for _ in 0..4 {
+            let mut num_conflicts = 0;
+            while !restarter.should_restart() {
+                restarter.notify_conflict(1, 1);
+                num_conflicts += 1;
+            }
+            intervals.push(num_conflicts);
+            restarter.notify_restart();
+            restarter.notify_backtrack_to_root();
+        }
+        assert_eq!(intervals, vec![10, 10, 20, 10]);
+    }
+
+    #[test]
+    fn test_glucose_restarts_when_recent_clauses_are_worse() {
+        let mut restarter = Restarter::new(RestartStrategy::Glucose, 10);
+        for _ in 0..1000 {
+            restarter.notify_conflict(5, 100);
+        }
+        assert!(!restarter.should_restart());
+
+        for _ in 0..LBD_WINDOW_SIZE {
+            restarter.notify_conflict(20, 100);
+        }
+        assert!(restarter.should_restart());
+
+        restarter.notify_restart();
+        assert!(!restarter.should_restart());
+    }
+}
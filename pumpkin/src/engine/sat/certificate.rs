@@ -0,0 +1,58 @@
+use std::fs::File;
+use std::io::Write;
+
+use log::warn;
+
+use crate::basic_types::Literal;
+
+//writes the clauses derived and deleted by the solver in the DRAT format
+//  nothing is written if certificate output is disabled
+pub struct Certificate {
+    certificate_file: Option<File>,
+}
+
+impl Certificate {
+    pub fn new(certificate_file: Option<File>) -> Certificate {
+        Certificate { certificate_file }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.certificate_file.is_some()
+    }
+
+    pub fn add_clause(&mut self, literals: &[Literal]) {
+        self.write_clause("", literals);
+    }
+
+    pub fn delete_clause(&mut self, literals: &[Literal]) {
+        self.write_clause("d ", literals);
+    }
+
+    fn write_clause(&mut self, prefix: &str, literals: &[Literal]) {
+        if let Some(certificate_file) = &mut self.certificate_file {
+            if let Err(write_error) = Certificate::write_line(certificate_file, prefix, literals) {
+                warn!(
+                    "Failed to update the certificate file, error message: {}",
+                    write_error
+                );
+            }
+        }
+    }
+
+    fn write_line(
+        certificate_file: &mut File,
+        prefix: &str,
+        literals: &[Literal],
+    ) -> std::io::Result<()> {
+        certificate_file.write_all(prefix.as_bytes())?;
+        for lit in literals {
+            if lit.is_negative() {
+                certificate_file.write_all("-".as_bytes())?;
+            }
+            certificate_file.write_all(
+                format!("{} ", &lit.get_propositional_variable().index().to_string()).as_bytes(),
+            )?;
+        }
+        certificate_file.write_all("0\n".as_bytes())
+    }
+}
@@ -39,7 +39,7 @@ impl ClauseAllocator {
         } else {
             //reuse a clause reference from the deleted clause pool
             let clause_reference = self.deleted_clause_ids.pop().unwrap();
-            self.allocated_clauses[clause_reference.id as usize] =
+            self.allocated_clauses[clause_reference.id as usize - 1] =
                 Clause::new(literals, is_learned);
 
             pumpkin_assert_simple!(
@@ -121,3 +121,32 @@ impl std::fmt::Display for ClauseAllocator {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::basic_types::{Literal, PropositionalVariable};
+
+    use super::*;
+
+    fn literals(indices: &[u32]) -> Vec<Literal> {
+        indices
+            .iter()
+            .map(|index| Literal::new(PropositionalVariable::new(*index), true))
+            .collect()
+    }
+
+    #[test]
+    fn test_deleted_clause_id_is_reused_for_the_new_clause() {
+        let mut clause_allocator = ClauseAllocator::new();
+        let deleted = clause_allocator.create_clause(literals(&[1, 2]), true);
+        let kept = clause_allocator.create_clause(literals(&[3, 4]), true);
+        clause_allocator.delete_clause(deleted);
+
+        let reused = clause_allocator.create_clause(literals(&[5, 6]), true);
+        assert!(reused == deleted);
+        assert!(!clause_allocator[reused].is_deleted());
+        assert!(clause_allocator[reused].get_literal_slice() == literals(&[5, 6]));
+        //the clause allocated after the deleted one is left untouched
+        assert!(clause_allocator[kept].get_literal_slice() == literals(&[3, 4]));
+    }
+}
@@ -0,0 +1,379 @@
+use crate::basic_types::{ClauseReference, Literal, PropagationStatusClausal};
+use crate::pumpkin_asserts::*;
+
+use super::SATEngineDataStructures;
+
+//the maximum number of literals inspected during subsumption in one round of inprocessing
+const SUBSUMPTION_BUDGET: u64 = 10_000_000;
+//the maximum number of clauses vivified in one round of inprocessing
+const VIVIFICATION_BUDGET: usize = 1000;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ClauseTier {
+    Permanent,
+    Core,
+    Tier2,
+    Local,
+}
+
+//the clauses are processed as one list, so that clauses can be replaced without losing track of the tier they belong to
+//  deleted clauses are set to None, since their clause references may be reused for new clauses
+type ClauseList = Vec<Option<(ClauseReference, ClauseTier)>>;
+
+//simplification of the clause database at the root level
+//  each round removes root-satisfied clauses and root-falsified literals, applies subsumption and self-subsuming resolution,
+//  and vivifies the core and tier2 learned clauses
+//  clauses are simplified by adding the simplified clause and deleting the original, both of which are logged in the certificate
+impl SATEngineDataStructures {
+    //root-level unit clauses found during inprocessing are enqueued and end the round early
+    //  the solver is expected to propagate them before continuing the search
+    pub fn inprocess(&mut self) {
+        pumpkin_assert_simple!(
+            self.assignments_propositional.is_at_the_root_level()
+                && self.is_clausal_propagation_at_fixed_point(),
+            "Inprocessing can only be done at the root level after propagation."
+        );
+
+        //a root assignment may have been propagated by a clause that is deleted as satisfied below
+        //  the root assignments are therefore logged as unit clauses, so the certificate does not depend on the deleted clauses
+        //  assignments propagated by CP propagators are skipped, since they cannot be derived from the clauses by unit propagation
+        //  note that the certificate does not cover the explanations of CP propagators, so it cannot be checked if there are any
+        //  root assignments are never undone, so only the ones added since the previous round still need to be logged
+        let assignments = &self.assignments_propositional;
+        for literal in &assignments.trail[self.num_root_units_logged..] {
+            let reason_code = assignments.get_literal_reason_code(*literal);
+            if *literal != assignments.true_literal
+                && self
+                    .clause_allocator
+                    .is_reason_code_linked_to_a_clause(reason_code)
+            {
+                self.certificate.add_clause(&[*literal]);
+            }
+        }
+        self.num_root_units_logged = assignments.trail.len();
+
+        let mut clauses = self.take_clauses();
+
+        let _ = self.remove_root_assignments(&mut clauses)
+            || self.subsume(&mut clauses)
+            || self.vivify(&mut clauses);
+
+        self.restore_clauses(clauses);
+    }
+
+    fn take_clauses(&mut self) -> ClauseList {
+        let tiers = [
+            (&mut self.permanent_clauses, ClauseTier::Permanent),
+            (&mut self.learned_clauses_core, ClauseTier::Core),
+            (&mut self.learned_clauses_tier2, ClauseTier::Tier2),
+            (&mut self.learned_clauses_local, ClauseTier::Local),
+        ];
+        tiers
+            .into_iter()
+            .flat_map(|(clause_references, tier)| {
+                std::mem::take(clause_references)
+                    .into_iter()
+                    .map(move |clause_reference| Some((clause_reference, tier)))
+            })
+            .collect()
+    }
+
+    fn restore_clauses(&mut self, clauses: ClauseList) {
+        for (clause_reference, tier) in clauses.into_iter().flatten() {
+            match tier {
+                ClauseTier::Permanent => self.permanent_clauses.push(clause_reference),
+                ClauseTier::Core => self.learned_clauses_core.push(clause_reference),
+                ClauseTier::Tier2 => self.learned_clauses_tier2.push(clause_reference),
+                ClauseTier::Local => self.learned_clauses_local.push(clause_reference),
+            }
+        }
+    }
+
+    //returns true if a unit clause was found
+    fn remove_root_assignments(&mut self, clauses: &mut ClauseList) -> bool {
+        for clause in clauses.iter_mut() {
+            let Some((clause_reference, _)) = *clause else {
+                continue;
+            };
+            let assignments = &self.assignments_propositional;
+            let literals = self.clause_allocator[clause_reference].get_literal_slice();
+
+            if literals
+                .iter()
+                .any(|literal| assignments.is_literal_assigned_true(*literal))
+            {
+                self.remove_clause(clause_reference);
+                *clause = None;
+            } else if literals
+                .iter()
+                .any(|literal| assignments.is_literal_assigned_false(*literal))
+            {
+                let unassigned_literals: Vec<Literal> = literals
+                    .iter()
+                    .copied()
+                    .filter(|literal| assignments.is_literal_unassigned(*literal))
+                    .collect();
+                if self.replace_clause(clause, unassigned_literals, true) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    //removes clauses that contain all literals of another clause (subsumption)
+    //  and removes a literal from a clause if another clause contains its negation and a subset of the remaining literals (self-subsuming resolution)
+    //  returns true if a unit clause was found
+    fn subsume(&mut self, clauses: &mut ClauseList) -> bool {
+        let num_literal_codes =
+            2 * self.assignments_propositional.num_propositional_variables() as usize;
+
+        let mut occurrences: Vec<Vec<usize>> = vec![vec![]; num_literal_codes];
+        for (index, (clause_reference, _)) in clauses
+            .iter()
+            .enumerate()
+            .filter_map(|(index, clause)| clause.map(|clause| (index, clause)))
+        {
+            for literal in self.clause_allocator[clause_reference].get_literal_slice() {
+                occurrences[literal.to_u32() as usize].push(index);
+            }
+        }
+
+        //shorter clauses are more likely to subsume other clauses, so they are considered first
+        let mut order: Vec<usize> = (0..clauses.len())
+            .filter(|i| clauses[*i].is_some())
+            .collect();
+        order.sort_by_key(|index| self.clause_allocator[clauses[*index].unwrap().0].len());
+
+        let mut is_marked = vec![false; num_literal_codes];
+        let mut num_inspected_literals: u64 = 0;
+        for index in order {
+            if num_inspected_literals > SUBSUMPTION_BUDGET {
+                break;
+            }
+            let Some((clause_reference, tier)) = clauses[index] else {
+                continue;
+            };
+
+            let subsuming_literals = self.clause_allocator[clause_reference]
+                .get_literal_slice()
+                .to_vec();
+            for literal in &subsuming_literals {
+                is_marked[literal.to_u32() as usize] = true;
+            }
+
+            //the other clause is subsumed if all literals of this clause appear in it
+            //  it suffices to look at the clauses that contain the literal with the fewest occurrences
+            let least_occurring_literal = *subsuming_literals
+                .iter()
+                .min_by_key(|literal| occurrences[literal.to_u32() as usize].len())
+                .unwrap();
+            for &other_index in &occurrences[least_occurring_literal.to_u32() as usize] {
+                let Some((other_clause_reference, other_tier)) = clauses[other_index] else {
+                    continue;
+                };
+                if other_index == index {
+                    continue;
+                }
+
+                let other_literals =
+                    self.clause_allocator[other_clause_reference].get_literal_slice();
+                num_inspected_literals += other_literals.len() as u64;
+                let num_shared_literals = other_literals
+                    .iter()
+                    .filter(|literal| is_marked[literal.to_u32() as usize])
+                    .count();
+                if num_shared_literals == subsuming_literals.len() {
+                    self.remove_clause(other_clause_reference);
+                    clauses[other_index] = None;
+
+                    //the subsuming clause takes over the role of the subsumed clause, so it may not be deleted later
+                    if matches!(other_tier, ClauseTier::Permanent | ClauseTier::Core)
+                        && matches!(tier, ClauseTier::Tier2 | ClauseTier::Local)
+                    {
+                        clauses[index] = Some((clause_reference, ClauseTier::Core));
+                    }
+                }
+            }
+
+            //the other clause contains the negation of a literal of this clause and all other literals of this clause
+            //  then the negated literal can be removed from the other clause
+            for &literal in &subsuming_literals {
+                for &other_index in &occurrences[(!literal).to_u32() as usize] {
+                    let Some((other_clause_reference, _)) = clauses[other_index] else {
+                        continue;
+                    };
+
+                    let other_literals =
+                        self.clause_allocator[other_clause_reference].get_literal_slice();
+                    num_inspected_literals += other_literals.len() as u64;
+                    //the clause may have been strengthened before, so the negated literal might no longer be present
+                    if other_literals.len() < subsuming_literals.len()
+                        || !other_literals.contains(&!literal)
+                    {
+                        continue;
+                    }
+                    let num_shared_literals = other_literals
+                        .iter()
+                        .filter(|literal| is_marked[literal.to_u32() as usize])
+                        .count();
+                    if num_shared_literals == subsuming_literals.len() - 1 {
+                        let strengthened_literals: Vec<Literal> = other_literals
+                            .iter()
+                            .copied()
+                            .filter(|other_literal| *other_literal != !literal)
+                            .collect();
+                        if self.replace_clause(
+                            &mut clauses[other_index],
+                            strengthened_literals,
+                            true,
+                        ) {
+                            return true;
+                        }
+                    }
+                }
+            }
+
+            for literal in &subsuming_literals {
+                is_marked[literal.to_u32() as usize] = false;
+            }
+        }
+        false
+    }
+
+    //shortens learned clauses by propagating the negation of their literals one by one
+    //  if the negations lead to a conflict or imply one of the other literals, the remaining literals are not needed
+    //  literals that are falsified by the negations of the previous literals are not needed either
+    //  a clause is vivified at most once, unless it gets replaced by a simplified clause
+    //  returns true if a unit clause was found
+    fn vivify(&mut self, clauses: &mut ClauseList) -> bool {
+        let mut num_vivified_clauses = 0;
+        for clause in clauses.iter_mut() {
+            if num_vivified_clauses == VIVIFICATION_BUDGET {
+                break;
+            }
+            let Some((clause_reference, tier)) = *clause else {
+                continue;
+            };
+            if !matches!(tier, ClauseTier::Core | ClauseTier::Tier2)
+                || self.clause_allocator[clause_reference].is_vivified()
+            {
+                continue;
+            }
+            num_vivified_clauses += 1;
+
+            //the clause itself should not take part in propagation, otherwise it would always appear to be implied
+            self.clausal_propagator.remove_clause_consideration(
+                &self.clause_allocator[clause_reference],
+                clause_reference,
+            );
+
+            let literals = self.clause_allocator[clause_reference]
+                .get_literal_slice()
+                .to_vec();
+            let mut vivified_literals = vec![];
+            for literal in &literals {
+                let assignments = &mut self.assignments_propositional;
+                if assignments.is_literal_assigned_true(*literal) {
+                    vivified_literals.push(*literal);
+                    break;
+                } else if assignments.is_literal_assigned_false(*literal) {
+                    continue;
+                }
+
+                vivified_literals.push(*literal);
+                assignments.increase_decision_level();
+                assignments.enqueue_decision_literal(!*literal);
+                if let PropagationStatusClausal::ConflictDetected { reason_code: _ } =
+                    self.propagate_clauses()
+                {
+                    break;
+                }
+            }
+            self.backtrack_to_root_without_saving_phases();
+
+            if vivified_literals.len() < literals.len() {
+                if self.replace_clause(clause, vivified_literals, false) {
+                    return true;
+                }
+                if let Some((vivified_clause_reference, _)) = *clause {
+                    self.clause_allocator[vivified_clause_reference].mark_vivified();
+                }
+            } else {
+                self.clausal_propagator.start_watching_clause_unchecked(
+                    &self.clause_allocator[clause_reference],
+                    clause_reference,
+                );
+                self.clause_allocator[clause_reference].mark_vivified();
+            }
+        }
+        false
+    }
+
+    //undoes the assignments made during vivification
+    //  unlike a regular backtrack, the saved phases are not updated, since the assignments do not come from the search
+    fn backtrack_to_root_without_saving_phases(&mut self) {
+        let assignments = &mut self.assignments_propositional;
+        if assignments.is_at_the_root_level() {
+            return;
+        }
+
+        let root_trail_size = assignments.trail_delimiter[0] as usize;
+        while assignments.trail.len() > root_trail_size {
+            let literal = assignments.pop_trail();
+            self.propositional_variable_selector
+                .restore(literal.get_propositional_variable());
+        }
+        assignments.synchronise(0);
+        self.clausal_propagator
+            .synchronise(assignments.num_assigned_propositional_variables() as usize);
+    }
+
+    //the new clause takes the place of the old clause in the clause list, and the old clause is deleted
+    //  a unit clause is enqueued as a root assignment instead, in which case true is returned
+    fn replace_clause(
+        &mut self,
+        clause: &mut Option<(ClauseReference, ClauseTier)>,
+        literals: Vec<Literal>,
+        is_watched: bool,
+    ) -> bool {
+        pumpkin_assert_moderate!(!literals.is_empty());
+        let (old_clause_reference, tier) = clause.unwrap();
+
+        self.certificate.add_clause(&literals);
+
+        let is_unit_clause = literals.len() == 1;
+        if is_unit_clause {
+            pumpkin_assert_moderate!(self
+                .assignments_propositional
+                .is_literal_unassigned(literals[0]));
+            self.assignments_propositional
+                .enqueue_decision_literal(literals[0]);
+            *clause = None;
+        } else {
+            let old_clause = &self.clause_allocator[old_clause_reference];
+            let (lbd, activity) = (old_clause.get_lbd(), old_clause.get_activity());
+
+            let clause_reference = self
+                .clause_allocator
+                .create_clause(literals, tier != ClauseTier::Permanent);
+            let new_clause = &mut self.clause_allocator[clause_reference];
+            if lbd < new_clause.get_lbd() {
+                new_clause.update_lbd(lbd);
+            }
+            new_clause.increase_activity(activity);
+            self.clausal_propagator.start_watching_clause_unchecked(
+                &self.clause_allocator[clause_reference],
+                clause_reference,
+            );
+            *clause = Some((clause_reference, tier));
+        }
+
+        if is_watched {
+            self.remove_clause(old_clause_reference);
+        } else {
+            self.delete_unwatched_clause(old_clause_reference);
+        }
+        is_unit_clause
+    }
+}
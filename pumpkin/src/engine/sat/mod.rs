@@ -1,13 +1,18 @@
 mod assignments_propositional;
+mod certificate;
 mod clause_allocator;
+mod inprocessing;
 mod propositional_value_selector;
 mod propositional_variable_selector;
 mod sat_engine_data_structures;
 
 pub use assignments_propositional::AssignmentsPropositional;
+pub use certificate::Certificate;
 pub use clause_allocator::ClauseAllocator;
 pub use propositional_value_selector::PropositionalValueSelector;
-pub use propositional_variable_selector::PropositionalVariableSelector;
+pub use propositional_variable_selector::{
+    PropositionalVariableSelector, VariableOrderingHeuristic,
+};
 pub use sat_engine_data_structures::{
     LearnedClauseSortingStrategy, SATDataStructuresInternalParameters, SATEngineDataStructures,
 };
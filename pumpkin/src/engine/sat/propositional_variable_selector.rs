@@ -1,11 +1,35 @@
 use super::AssignmentsPropositional;
 use crate::basic_types::{KeyValueHeap, PropositionalVariable};
+use crate::pumpkin_asserts::*;
+
+#[derive(Clone)]
+pub enum VariableOrderingHeuristic {
+    /// Branches on the variable with the highest activity, where activities are bumped during
+    /// conflict analysis. The random seed decides the initial order of the variables.
+    Vsids(i64),
+    /// Branches on the unassigned variable that comes first in the given order. Variables that
+    /// are not in the order come last. Activities are not bumped, so the order never changes.
+    StaticPriority(Vec<PropositionalVariable>),
+    /// Starts from the given order as with the static priority, but bumps activities as with
+    /// VSIDS, so the order is gradually taken over by the activities.
+    PriorityThenVsids {
+        order: Vec<PropositionalVariable>,
+        /// Multiplies the initial activities given by the order. A larger weight makes the order
+        /// last longer before the bumped activities take over.
+        priority_weight: f64,
+        /// Whether the weighted priorities are added to the activities again at every restart.
+        reinject_at_restart: bool,
+    },
+}
 
 pub struct PropositionalVariableSelector {
     heap: KeyValueHeap,
     increment: f64,
     max_threshold: f64,
     decay_factor: f64,
+    is_bumping_enabled: bool,
+    //the priorities that are added to the activities at every restart, empty if this is disabled
+    reinjected_priorities: Vec<(PropositionalVariable, f64)>,
 }
 
 impl PropositionalVariableSelector {
@@ -15,23 +39,88 @@ impl PropositionalVariableSelector {
             increment: 1.0,
             max_threshold: 1e100,
             decay_factor: 0.95,
+            is_bumping_enabled: true,
+            reinjected_priorities: vec![],
         }
     }
 
-    pub fn reset(&mut self, random_seed: i64) {
-        self.heap.reset(random_seed);
+    pub fn reset(&mut self, heuristic: &VariableOrderingHeuristic) {
         self.increment = 1.0;
+        self.reinjected_priorities.clear();
+        match heuristic {
+            VariableOrderingHeuristic::Vsids(random_seed) => {
+                self.heap.reset(*random_seed);
+                self.is_bumping_enabled = true;
+            }
+            VariableOrderingHeuristic::StaticPriority(order) => {
+                self.set_priorities(order, 1.0);
+                self.is_bumping_enabled = false;
+            }
+            VariableOrderingHeuristic::PriorityThenVsids {
+                order,
+                priority_weight,
+                reinject_at_restart,
+            } => {
+                let priorities = self.set_priorities(order, *priority_weight);
+                if *reinject_at_restart {
+                    self.reinjected_priorities = priorities;
+                }
+                self.is_bumping_enabled = true;
+            }
+        }
+    }
+
+    //the first variable in the order gets the highest initial activity, and the last variable an activity equal to the weight
+    //  variables not in the order keep an activity of zero
+    fn set_priorities(
+        &mut self,
+        order: &[PropositionalVariable],
+        priority_weight: f64,
+    ) -> Vec<(PropositionalVariable, f64)> {
+        pumpkin_assert_simple!(priority_weight > 0.0);
+        self.heap.reset(-2);
+        let priorities: Vec<(PropositionalVariable, f64)> = order
+            .iter()
+            .enumerate()
+            .map(|(position, variable)| {
+                (*variable, priority_weight * (order.len() - position) as f64)
+            })
+            .collect();
+        for (variable, priority) in &priorities {
+            pumpkin_assert_simple!(
+                self.heap.get_value(variable.index()) == 0.0,
+                "Variables may appear only once in the priority order."
+            );
+            self.heap.increment(variable.index(), *priority);
+        }
+        priorities
     }
 
     pub fn bump_activity(&mut self, variable: PropositionalVariable) {
+        if !self.is_bumping_enabled {
+            return;
+        }
+        self.increase_activity(variable, self.increment);
+    }
+
+    //the priorities are scaled by the current increment, so compared to the bumps that follow
+    //  they weigh as much as the initial priorities did at the start of the search
+    pub fn notify_restart(&mut self) {
+        for index in 0..self.reinjected_priorities.len() {
+            let (variable, priority) = self.reinjected_priorities[index];
+            self.increase_activity(variable, priority * self.increment);
+        }
+    }
+
+    fn increase_activity(&mut self, variable: PropositionalVariable, mut amount: f64) {
         //scale the activities if the values are too large
-        //let activity = self.heap.get_value(variable.index());
-        //if activity + self.increment >= self.max_threshold {
-        //    self.heap.divide_values(self.max_threshold);
-        //    self.increment /= self.max_threshold;
-        // }
-        //now perform the standard bumping
-        //self.heap.increment(variable.index(), self.increment);
+        let activity = self.heap.get_value(variable.index());
+        if activity + amount >= self.max_threshold {
+            self.heap.divide_values(self.max_threshold);
+            self.increment /= self.max_threshold;
+            amount /= self.max_threshold;
+        }
+        self.heap.increment(variable.index(), amount);
     }
 
     pub fn restore(&mut self, variable: PropositionalVariable) {
@@ -69,3 +158,93 @@ impl PropositionalVariableSelector {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_selector_and_assignments(
+        num_variables: usize,
+    ) -> (PropositionalVariableSelector, AssignmentsPropositional) {
+        let mut selector = PropositionalVariableSelector::new();
+        let mut assignments = AssignmentsPropositional::new();
+        for _ in 0..num_variables {
+            selector.grow();
+            assignments.grow();
+        }
+        (selector, assignments)
+    }
+
+    fn next_variable_index(
+        selector: &mut PropositionalVariableSelector,
+        assignments: &AssignmentsPropositional,
+    ) -> u32 {
+        selector.peek_next_variable(assignments).unwrap().index()
+    }
+
+    #[test]
+    fn test_static_priority_ignores_bumps() {
+        let (mut selector, assignments) = create_selector_and_assignments(4);
+        let order = [3, 1, 2].map(PropositionalVariable::new).to_vec();
+        selector.reset(&VariableOrderingHeuristic::StaticPriority(order));
+        assert_eq!(next_variable_index(&mut selector, &assignments), 3);
+
+        for _ in 0..10 {
+            selector.bump_activity(PropositionalVariable::new(0));
+        }
+        assert_eq!(next_variable_index(&mut selector, &assignments), 3);
+    }
+
+    #[test]
+    fn test_priority_then_vsids_follows_bumps() {
+        let (mut selector, assignments) = create_selector_and_assignments(4);
+        let order = [3, 1, 2].map(PropositionalVariable::new).to_vec();
+        selector.reset(&VariableOrderingHeuristic::PriorityThenVsids {
+            order,
+            priority_weight: 1.0,
+            reinject_at_restart: false,
+        });
+        assert_eq!(next_variable_index(&mut selector, &assignments), 3);
+
+        //the first variable in the order starts with an activity of three
+        for _ in 0..4 {
+            selector.bump_activity(PropositionalVariable::new(0));
+        }
+        assert_eq!(next_variable_index(&mut selector, &assignments), 0);
+    }
+
+    #[test]
+    fn test_priority_weight_delays_vsids() {
+        let (mut selector, assignments) = create_selector_and_assignments(4);
+        let order = [3, 1, 2].map(PropositionalVariable::new).to_vec();
+        selector.reset(&VariableOrderingHeuristic::PriorityThenVsids {
+            order,
+            priority_weight: 10.0,
+            reinject_at_restart: false,
+        });
+
+        for _ in 0..4 {
+            selector.bump_activity(PropositionalVariable::new(0));
+        }
+        assert_eq!(next_variable_index(&mut selector, &assignments), 3);
+    }
+
+    #[test]
+    fn test_priorities_are_reinjected_at_restarts() {
+        let (mut selector, assignments) = create_selector_and_assignments(4);
+        let order = [3, 1, 2].map(PropositionalVariable::new).to_vec();
+        selector.reset(&VariableOrderingHeuristic::PriorityThenVsids {
+            order,
+            priority_weight: 1.0,
+            reinject_at_restart: true,
+        });
+
+        for _ in 0..4 {
+            selector.bump_activity(PropositionalVariable::new(0));
+        }
+        assert_eq!(next_variable_index(&mut selector, &assignments), 0);
+
+        selector.notify_restart();
+        assert_eq!(next_variable_index(&mut selector, &assignments), 3);
+    }
+}
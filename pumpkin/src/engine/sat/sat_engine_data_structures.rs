@@ -7,7 +7,7 @@ use crate::{
 };
 
 use super::{
-    AssignmentsPropositional, ClauseAllocator, PropositionalValueSelector,
+    AssignmentsPropositional, Certificate, ClauseAllocator, PropositionalValueSelector,
     PropositionalVariableSelector,
 };
 
@@ -20,15 +20,30 @@ pub struct SATEngineDataStructures {
     pub clausal_propagator: ClausalPropagator,
     pub clause_allocator: ClauseAllocator,
     pub permanent_clauses: Vec<ClauseReference>,
-    pub learned_clauses: Vec<ClauseReference>,
+    //learned clauses are kept in three tiers
+    //  core clauses are never deleted, tier2 clauses are kept as long as they are used, and local clauses are reduced based on their activity
+    pub learned_clauses_core: Vec<ClauseReference>,
+    pub learned_clauses_tier2: Vec<ClauseReference>,
+    pub learned_clauses_local: Vec<ClauseReference>,
     pub explanation_clauses: Vec<ClauseReference>,
     pub assumptions: Vec<Literal>,
+    pub certificate: Certificate,
+    //the root assignments before this index on the trail have been logged in the certificate by inprocessing
+    pub num_root_units_logged: usize,
     parameters: SATDataStructuresInternalParameters,
     clause_bump_increment: f32,
+    num_local_learned_clauses_max: u64,
 }
 
 pub struct SATDataStructuresInternalParameters {
+    /// The number of local learned clauses before the learned clauses are reduced for the first time.
     pub num_learned_clauses_max: u64,
+    /// After every reduction, the number of local learned clauses before the next reduction grows by this factor.
+    pub learned_clauses_max_growth_factor: f64,
+    /// Learned clauses with at most this LBD are never deleted.
+    pub lbd_threshold_core: u32,
+    /// Learned clauses with at most this LBD are kept as long as they are used in conflict analysis.
+    pub lbd_threshold_tier2: u32,
     pub max_clause_activity: f32,
     pub clause_activity_decay_factor: f32,
     pub learned_clause_sorting_strategy: LearnedClauseSortingStrategy,
@@ -47,11 +62,16 @@ impl SATEngineDataStructures {
             clausal_propagator: ClausalPropagator::new(),
             clause_allocator: ClauseAllocator::new(),
             permanent_clauses: vec![],
-            learned_clauses: vec![],
+            learned_clauses_core: vec![],
+            learned_clauses_tier2: vec![],
+            learned_clauses_local: vec![],
             explanation_clauses: vec![],
             propositional_variable_selector: PropositionalVariableSelector::new(),
             propositional_value_selector: PropositionalValueSelector::new(),
             assumptions: vec![],
+            certificate: Certificate::new(None),
+            num_root_units_logged: 0,
+            num_local_learned_clauses_max: parameters.num_learned_clauses_max,
             parameters,
             clause_bump_increment: 1.0,
         }
@@ -118,13 +138,38 @@ impl SATEngineDataStructures {
         let clause_reference = self.clause_allocator.create_clause(literals, is_learned);
         let clause = self.clause_allocator.get_clause(clause_reference);
 
-        self.permanent_clauses.push(clause_reference);
+        if !is_learned {
+            self.permanent_clauses.push(clause_reference);
+        }
         self.clausal_propagator
             .start_watching_clause_unchecked(clause, clause_reference);
 
         clause_reference
     }
 
+    //the learned clause is placed in the tier that corresponds to its lbd
+    pub fn add_learned_clause_unchecked(
+        &mut self,
+        literals: Vec<Literal>,
+        lbd: u32,
+    ) -> ClauseReference {
+        let clause_reference = self.add_clause_unchecked(literals, true);
+
+        if lbd < self.clause_allocator[clause_reference].get_lbd() {
+            self.clause_allocator[clause_reference].update_lbd(lbd);
+        }
+
+        if lbd <= self.parameters.lbd_threshold_core {
+            self.learned_clauses_core.push(clause_reference);
+        } else if lbd <= self.parameters.lbd_threshold_tier2 {
+            self.learned_clauses_tier2.push(clause_reference);
+        } else {
+            self.learned_clauses_local.push(clause_reference);
+        }
+
+        clause_reference
+    }
+
     pub fn add_explanation_clause_unchecked(
         &mut self,
         explanation_literals: Vec<Literal>,
@@ -154,15 +199,37 @@ impl SATEngineDataStructures {
             "For now learned clause reductions can only be done at the root level."
         );
 
-        if self.learned_clauses.len() <= self.parameters.num_learned_clauses_max as usize {
+        if self.learned_clauses_local.len() <= self.num_local_learned_clauses_max as usize {
             return;
         }
 
-        //sort learned clauses
+        //the reductions become less frequent over time, so that more learned clauses are kept in long runs
+        self.num_local_learned_clauses_max = (self.num_local_learned_clauses_max as f64
+            * self.parameters.learned_clauses_max_growth_factor)
+            as u64;
+
+        //clauses are moved between the tiers first
+        //  tier2 clauses that have not been used since the last reduction are moved to the local tier
+        //  local clauses whose lbd has improved since they were learned are promoted
+        let tier2_clauses = std::mem::take(&mut self.learned_clauses_tier2);
+        let local_clauses = std::mem::take(&mut self.learned_clauses_local);
+        for clause_reference in tier2_clauses.into_iter().chain(local_clauses) {
+            let clause = &mut self.clause_allocator[clause_reference];
+            if clause.get_lbd() <= self.parameters.lbd_threshold_core {
+                self.learned_clauses_core.push(clause_reference);
+            } else if clause.get_lbd() <= self.parameters.lbd_threshold_tier2 && clause.is_used() {
+                self.learned_clauses_tier2.push(clause_reference);
+            } else {
+                self.learned_clauses_local.push(clause_reference);
+            }
+            clause.clear_used();
+        }
+
+        //sort local learned clauses
         //the ordering is such that the better clauses are in the front
         //  note that this is not the most efficient sorting comparison, but will do for now
         //  e.g., sort_by_lbd could be moved out, and the comparison of floats could be changed possibly
-        self.learned_clauses
+        self.learned_clauses_local
             .sort_unstable_by(|clause_reference1, clause_reference2| {
                 let clause1 = self.clause_allocator.get_clause(*clause_reference1);
                 let clause2 = self.clause_allocator.get_clause(*clause_reference2);
@@ -189,16 +256,12 @@ impl SATEngineDataStructures {
                 }
             });
 
-        //the clauses at the back of the array are the 'bad' clauses
-        let mut num_clauses_to_remove =
-            self.learned_clauses.len() as u64 - self.parameters.num_learned_clauses_max;
-        for i in 0..self.learned_clauses.len() {
-            if num_clauses_to_remove == 0 {
-                break;
-            }
-
-            let i_rev = self.learned_clauses.len() - 1 - i;
-            let clause_reference = self.learned_clauses[i_rev];
+        //the clauses at the back of the array are the 'bad' clauses, half of the local clauses are removed
+        let mut num_clauses_to_remove = self.learned_clauses_local.len() / 2;
+        let mut index = self.learned_clauses_local.len();
+        while index > 0 && num_clauses_to_remove > 0 {
+            index -= 1;
+            let clause_reference = self.learned_clauses_local[index];
 
             if self.clause_allocator[clause_reference].is_protected_aganst_deletion() {
                 self.clause_allocator[clause_reference].clear_protection_against_deletion();
@@ -209,17 +272,11 @@ impl SATEngineDataStructures {
             //  clause removal is done in several steps
 
             //  remove the reference from the learned clause vector
-            //      note that because some clauses may be protected from deletion, we need to do more than a simple 'pop' operation
-            self.learned_clauses[i_rev] = *self.learned_clauses.last().unwrap();
-            self.learned_clauses.pop();
-
-            //  now remove the clause from the watch list
-            self.clausal_propagator.remove_clause_consideration(
-                &self.clause_allocator[clause_reference],
-                clause_reference,
-            );
-            //  finally delete the clause
-            self.clause_allocator.delete_clause(clause_reference);
+            //      the last clause takes its place, which has already been considered since we go from the back to the front
+            self.learned_clauses_local.swap_remove(index);
+
+            //  now remove the clause from the watch list and delete it
+            self.remove_clause(clause_reference);
 
             num_clauses_to_remove -= 1;
         }
@@ -229,12 +286,28 @@ impl SATEngineDataStructures {
             .debug_check_state(&self.assignments_propositional, &self.clause_allocator));
     }
 
-    fn peek_next_assumption_literal(&self) -> Option<Literal> {
-        assert!(
-            self.assumptions.is_empty(),
-            "Assumptions are not yet supported!"
+    //the clause is no longer watched and deleted, which is logged in the certificate
+    //  the caller is responsible for removing the clause reference from the clause lists
+    pub fn remove_clause(&mut self, clause_reference: ClauseReference) {
+        self.clausal_propagator.remove_clause_consideration(
+            &self.clause_allocator[clause_reference],
+            clause_reference,
         );
-        None
+        self.delete_unwatched_clause(clause_reference);
+    }
+
+    pub fn delete_unwatched_clause(&mut self, clause_reference: ClauseReference) {
+        self.certificate
+            .delete_clause(self.clause_allocator[clause_reference].get_literal_slice());
+        self.clause_allocator.delete_clause(clause_reference);
+    }
+
+    //the i-th assumption is decided at decision level i+1, this method is called after the decision level has been increased
+    //  so once the decision level exceeds the number of assumptions, all assumptions have been set
+    fn peek_next_assumption_literal(&self) -> Option<Literal> {
+        let decision_level = self.assignments_propositional.get_decision_level() as usize;
+        pumpkin_assert_moderate!(decision_level > 0);
+        self.assumptions.get(decision_level - 1).copied()
     }
 
     pub fn get_next_branching_decision(&mut self) -> Option<BranchingDecision> {
@@ -298,6 +371,14 @@ impl SATEngineDataStructures {
     }
 
     pub fn update_clause_lbd_and_bump_activity(&mut self, clause_reference: ClauseReference) {
+        if self
+            .clause_allocator
+            .get_clause(clause_reference)
+            .is_learned()
+        {
+            self.clause_allocator[clause_reference].mark_used();
+        }
+
         if self
             .clause_allocator
             .get_clause(clause_reference)
@@ -358,10 +439,14 @@ impl SATEngineDataStructures {
     }
 
     pub fn rescale_clause_activities(&mut self) {
-        self.learned_clauses.iter().for_each(|clause_reference| {
-            let clause = self.clause_allocator.get_mutable_clause(*clause_reference);
-            clause.divide_activity(self.parameters.max_clause_activity);
-        });
+        self.learned_clauses_core
+            .iter()
+            .chain(&self.learned_clauses_tier2)
+            .chain(&self.learned_clauses_local)
+            .for_each(|clause_reference| {
+                let clause = self.clause_allocator.get_mutable_clause(*clause_reference);
+                clause.divide_activity(self.parameters.max_clause_activity);
+            });
         self.clause_bump_increment /= self.parameters.max_clause_activity;
     }
 
@@ -457,9 +542,12 @@ impl Default for SATDataStructuresInternalParameters {
     fn default() -> Self {
         SATDataStructuresInternalParameters {
             num_learned_clauses_max: 4000,
+            learned_clauses_max_growth_factor: 1.1,
+            lbd_threshold_core: 2,
+            lbd_threshold_tier2: 6,
             max_clause_activity: 1e20,
             clause_activity_decay_factor: 0.99,
-            learned_clause_sorting_strategy: LearnedClauseSortingStrategy::Lbd,
+            learned_clause_sorting_strategy: LearnedClauseSortingStrategy::Activity,
         }
     }
 }
@@ -12,7 +12,14 @@ use basic_types::*;
 use clap::Parser;
 use engine::*;
 use log::{error, info, warn, LevelFilter};
+use parsers::dimacs::{
+    parse_assignment, parse_heuristic_file, parse_num_variables,
+    parse_variable_order_file, parse_variable_order_from_comment,
+};
+use signal_hook::consts::{SIGINT, SIGTERM};
 use std::fs::OpenOptions;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 use std::{io::Write, path::PathBuf};
 
@@ -24,37 +31,127 @@ use result::PumpkinResult;
 struct Args {
     /// The instance to solve. The file should have one of the following extensions:
     ///  * '.cnf' for SAT instances, given in the DIMACS format,
-    ///  * '.wcnf' for MaxSAT instances, given in the WDIMACS format.
+    ///  * '.wcnf' for MaxSAT instances, given in the WDIMACS format. Resource capacity constraints
+    ///    may be given on lines of the form 'r [resource] [capacity] [weight_1] [literal_1] ... 0'.
     instance_path: PathBuf,
 
     /// The output path for the DRAT certificate file. By default does not output any
-    /// certifying information.
+    /// certifying information. The certificate only covers the clauses, so it cannot be checked
+    /// for instances with capacity constraint lines.
     #[arg(long)]
     certificate_path: Option<PathBuf>,
 
-    /// The number of learned clauses that can be added to the clause database before clause
+    /// The number of local learned clauses that can be added to the clause database before clause
     /// deletion is triggered. This number could be exceeded temporarily but occasionally the
-    /// solver will delete learned clauses.
+    /// solver will delete half of the local learned clauses, after which the threshold grows by
+    /// 10%. Learned clauses with an LBD of at most 2 are never deleted, and clauses with an LBD of
+    /// at most 6 are kept as long as they are used in conflict analysis.
     #[arg(long = "threshold-learned-clauses", default_value_t = 4000)]
     threshold_learned_clauses: u64,
 
-    /// Decides which clauses will be removed when cleaning up the learned clauses.
-    #[arg(short = 'l', long = "learned-clause-sorting-strategy", default_value_t = LearnedClauseSortingStrategy::Lbd, value_parser = learned_clause_sorting_strategy_parser)]
+    /// Decides which local learned clauses will be removed when cleaning up the learned clauses.
+    #[arg(short = 'l', long = "learned-clause-sorting-strategy", default_value_t = LearnedClauseSortingStrategy::Activity, value_parser = learned_clause_sorting_strategy_parser)]
     learned_clause_sorting_strategy: LearnedClauseSortingStrategy,
 
-    /// The number of conflicts before a restart is triggered. This is a fixed-length restart
-    /// strategy.
+    /// Decides when the solver restarts: 'glucose' restarts when the recently learned clauses
+    /// have a worse LBD than average and blocks restarts when the trail is unusually large, 'luby'
+    /// restarts after a number of conflicts following the Luby sequence, and 'fixed' restarts
+    /// after a fixed number of conflicts, as in older versions of the solver.
+    #[arg(long = "restart-strategy", default_value_t = RestartStrategy::Glucose, value_parser = restart_strategy_parser)]
+    restart_strategy: RestartStrategy,
+
+    /// The number of conflicts before a restart is triggered when using the fixed restart
+    /// strategy. For the Luby restart strategy, this is the unit that the Luby sequence is
+    /// multiplied with.
     #[arg(long = "conflicts-per-restart", default_value_t = 4000)]
     conflicts_per_restart: i64,
 
-    /// The time budget for the solver, given in seconds.
-    #[arg(short = 't', long = "time-limit")]
-    time_limit: Option<u64>,
-
-    /// The random seed to use for the PRNG. This influences the initial order of the variables.
+    /// Disables the minimisation of learned clauses, which removes literals that are implied by
+    /// the other literals of the learned clause.
+    #[arg(long = "no-clause-minimisation", default_value_t = false)]
+    no_clause_minimisation: bool,
+
+    /// The number of conflicts between two rounds of inprocessing, which simplifies the clauses at
+    /// the root level by removing satisfied clauses and falsified literals, subsumption,
+    /// self-subsuming resolution, and vivification of learned clauses. Zero disables inprocessing.
+    #[arg(long = "conflicts-per-inprocessing", default_value_t = 20000)]
+    conflicts_per_inprocessing: u64,
+
+    /// The time budget for the solver, given in seconds. Fractions of a second are allowed, e.g.,
+    /// '0.5'.
+    #[arg(short = 't', long = "time-limit", value_parser = time_limit_parser)]
+    time_limit: Option<Duration>,
+
+    /// The number of conflicts after which the solver stops, counted over the whole run. Unlike
+    /// the time limit, this gives the same result regardless of the machine.
+    #[arg(long = "conflict-limit")]
+    conflict_limit: Option<u64>,
+
+    /// The number of propagations after which the solver stops, counted over the whole run.
+    /// Unlike the time limit, this gives the same result regardless of the machine.
+    #[arg(long = "propagation-limit")]
+    propagation_limit: Option<u64>,
+
+    /// The random seed to use for the PRNG. This influences the initial order of the variables
+    /// when branching with VSIDS.
     #[arg(long = "random-seed", default_value_t = -2)]
     random_seed: i64,
 
+    /// A file with a variable order, given as variable indices from the highest to the lowest
+    /// priority. The solver then branches on the variables in this order instead of using VSIDS.
+    #[arg(long = "variable-order-file", group = "variable_order")]
+    variable_order_file: Option<PathBuf>,
+
+    /// Reads the variable order from the first line of the instance, which should be a comment
+    /// line of the form 'c [variable_1] [variable_2] ...'. Only instances written by older versions
+    /// of the modeling tool start with such a line, newer ones come with a '.heur' file for
+    /// --heuristic-file instead. The solver then branches on the variables in this order instead
+    /// of using VSIDS.
+    #[arg(long = "heuristic-from-comment", default_value_t = false, group = "variable_order")]
+    heuristic_from_comment: bool,
+
+    /// A heuristic file as written by the modeling tool next to the instance, with a priority and
+    /// a preferred polarity for each variable. The solver branches on the variables from the
+    /// highest to the lowest priority instead of using VSIDS, and tries the preferred polarity
+    /// first. Only the ranking of the priorities is used, and the groups of variables are ignored.
+    #[arg(long = "heuristic-file", group = "variable_order")]
+    heuristic_file: Option<PathBuf>,
+
+    /// An assignment to start the search from, given as 'v' lines like in the output of the
+    /// solver, e.g. the '.init' or '.phases' file written by the modeling tool. The assigned
+    /// values are tried first when branching, after which phase saving takes over, so the
+    /// assignment does not need to be complete or feasible. Objective literals keep their
+    /// optimistic phase.
+    #[arg(long = "phase-hints")]
+    phase_hints: Option<PathBuf>,
+
+    /// A solution to start the search from, given as 'v' lines like in the output of the solver,
+    /// e.g. the '.init' file written by the modeling tool for a serial schedule. Every variable
+    /// of the instance must be assigned, either in the file or by propagating the assigned
+    /// values, so the auxiliary variables of a clausal encoding may be left out. The solver stops
+    /// with an error if the assignment is incomplete or violates the hard clauses. The solution
+    /// is the first upper bound, and the solver then only looks for better solutions.
+    #[arg(long = "initial-solution")]
+    initial_solution: Option<PathBuf>,
+
+    /// Only uses the variable order for the initial activities of VSIDS, so that the order is
+    /// gradually taken over by the activities during search.
+    #[arg(long = "priority-then-vsids", default_value_t = false, requires = "variable_order")]
+    priority_then_vsids: bool,
+
+    /// With `--priority-then-vsids`, multiplies the initial activities given by the variable
+    /// order. The last variable in the order starts with an activity equal to the weight, the
+    /// variable before it with twice the weight, and so on, while every conflict bumps the
+    /// activities by an amount that starts at one and grows by about 5% per conflict. A larger
+    /// weight therefore makes the order last longer.
+    #[arg(long = "priority-weight", default_value_t = 1.0, value_parser = priority_weight_parser, requires = "priority_then_vsids")]
+    priority_weight: f64,
+
+    /// With `--priority-then-vsids`, adds the weighted priorities of the variable order to the
+    /// activities again at every restart, so that the order keeps influencing the search.
+    #[arg(long = "reinject-priority-at-restart", default_value_t = false, requires = "priority_then_vsids")]
+    reinject_priority_at_restart: bool,
+
     /// Enables log message output from the solver
     #[arg(short = 'v', long = "verbose", default_value_t = false)]
     verbose: bool,
@@ -71,6 +168,14 @@ struct Args {
     /// The encoding to use for the upper bound constraint in an optimisation problem.
     #[arg(long = "upper-bound-encoding", default_value_t = UpperBoundEncoding::GTE, value_parser = upper_bound_encoding_parser)]
     upper_bound_encoding: UpperBoundEncoding,
+
+    /// How to search for the optimal solution in an optimisation problem: 'linear' improves
+    /// the best solution until no better one exists, 'binary' bisects the gap between the lower
+    /// bound and the best solution, 'progression' queries bounds growing geometrically from the
+    /// lower bound, and 'core-guided' (OLL) raises a lower bound from unsatisfiable cores until a
+    /// solution meets it. Lower bounds are reported as 'c lower bound [value]' lines.
+    #[arg(long = "optimisation-strategy", default_value_t = OptimisationStrategy::LinearSearch, value_parser = optimisation_strategy_parser)]
+    optimisation_strategy: OptimisationStrategy,
 }
 
 fn debug_check_feasibility_and_objective_value(
@@ -82,7 +187,9 @@ fn debug_check_feasibility_and_objective_value(
     let mut instance = Instance::new();
     instance.read_file(file_location, file_format)?;
 
-    if instance.are_hard_clauses_violated(solution) {
+    if instance.are_hard_clauses_violated(solution)
+        || instance.are_capacity_constraints_violated(solution)
+    {
         return Err(PumpkinError::InconsistentSolution);
     }
 
@@ -165,6 +272,7 @@ fn run() -> PumpkinResult<()> {
                 .create(true)
                 .read(true)
                 .write(true)
+                .truncate(true)
                 .open(path_buf.as_path())?,
         )
     } else {
@@ -172,31 +280,97 @@ fn run() -> PumpkinResult<()> {
     };
 
     let solver_options = SatisfactionSolverOptions {
+        restart_strategy: args.restart_strategy,
         conflicts_per_restart: args.conflicts_per_restart,
         certificate_file,
+        use_clause_minimisation: !args.no_clause_minimisation,
+        conflicts_per_inprocessing: (args.conflicts_per_inprocessing > 0)
+            .then_some(args.conflicts_per_inprocessing),
+        conflict_limit: args.conflict_limit,
+        propagation_limit: args.propagation_limit,
     };
 
     let mut pumpkin = Pumpkin::new(
         sat_options,
         solver_options,
+        args.optimisation_strategy,
         args.upper_bound_encoding,
-        args.time_limit.map(Duration::from_secs),
+        args.time_limit,
     );
     let path = args
         .instance_path
         .to_str()
         .ok_or(PumpkinError::InvalidInstanceFile)?;
     pumpkin.read_file(path, file_format)?;
-    pumpkin.reset_variable_selection(args.random_seed);
+
+    let variable_order = if let Some(order_path) = &args.variable_order_file {
+        Some(parse_variable_order_file(order_path, parse_num_variables(path)?)?)
+    } else if args.heuristic_from_comment {
+        Some(parse_variable_order_from_comment(path, parse_num_variables(path)?)?)
+    } else if let Some(heuristic_path) = &args.heuristic_file {
+        let (order, phases) = parse_heuristic_file(heuristic_path, parse_num_variables(path)?)?;
+        pumpkin.set_initial_phases(&phases);
+        Some(order)
+    } else {
+        None
+    };
+    let heuristic = match variable_order {
+        Some(order) if args.priority_then_vsids => VariableOrderingHeuristic::PriorityThenVsids {
+            order,
+            priority_weight: args.priority_weight,
+            reinject_at_restart: args.reinject_priority_at_restart,
+        },
+        Some(order) => VariableOrderingHeuristic::StaticPriority(order),
+        None => VariableOrderingHeuristic::Vsids(args.random_seed),
+    };
+    pumpkin.reset_variable_selection(&heuristic);
+
+    if let Some(phase_hints_path) = &args.phase_hints {
+        let phase_hints = parse_assignment(phase_hints_path, parse_num_variables(path)?)?;
+        pumpkin.set_initial_phases(&phase_hints);
+    }
+
+    //on SIGINT or SIGTERM the solver stops and reports the best solution found so far
+    //  a second signal terminates the process immediately, in case the solver does not stop in time
+    let termination_flag = Arc::new(AtomicBool::new(false));
+    for signal in [SIGINT, SIGTERM] {
+        signal_hook::flag::register_conditional_shutdown(signal, 1, Arc::clone(&termination_flag))?;
+        signal_hook::flag::register(signal, Arc::clone(&termination_flag))?;
+    }
+    pumpkin.set_termination_flag(Arc::clone(&termination_flag));
+
+    if let Some(initial_solution_path) = &args.initial_solution {
+        let num_variables = parse_num_variables(path)?;
+        let initial_solution = parse_assignment(initial_solution_path, num_variables)?;
+        //there is no solution yet when the solver is stopped this early, which is reported like a timeout during the search
+        match pumpkin.set_initial_solution(&initial_solution, num_variables) {
+            Err(PumpkinError::InitialSolutionTimeout) => {
+                warn!("Solving was stopped while completing the initial solution.");
+                println!("s UNKNOWN");
+                return Ok(());
+            }
+            result => result?,
+        }
+    }
 
     let pumpkin_output = pumpkin.solve();
 
+    if termination_flag.load(Ordering::Relaxed) {
+        warn!("Solving was interrupted, reporting the best solution found so far.");
+    }
+
     match pumpkin_output {
         PumpkinExecutionFlag::Feasible {
             ref feasible_solution,
             objective_value,
+            lower_bound,
         } => {
             println!("s SATISFIABLE");
+            println!(
+                "c lower bound {}, gap {}",
+                lower_bound,
+                objective_value - lower_bound
+            );
             println!("v {}", stringify_solution(feasible_solution));
             debug_check_feasibility_and_objective_value(
                 path,
@@ -210,7 +384,6 @@ fn run() -> PumpkinResult<()> {
             objective_value,
         } => {
             println!("s OPTIMAL");
-            println!("o {}", objective_value);
             println!("v {}", stringify_solution(optimal_solution));
             debug_check_feasibility_and_objective_value(
                 path,
@@ -248,6 +421,39 @@ fn learned_clause_sorting_strategy_parser(s: &str) -> Result<LearnedClauseSortin
     }
 }
 
+fn optimisation_strategy_parser(s: &str) -> Result<OptimisationStrategy, String> {
+    match s {
+        "linear" => Ok(OptimisationStrategy::LinearSearch),
+        "binary" => Ok(OptimisationStrategy::BinarySearch),
+        "progression" => Ok(OptimisationStrategy::ProgressionSearch),
+        "core-guided" => Ok(OptimisationStrategy::CoreGuided),
+        value => Err(format!("'{value}' is not a valid optimisation strategy.")),
+    }
+}
+
+fn restart_strategy_parser(s: &str) -> Result<RestartStrategy, String> {
+    match s {
+        "fixed" => Ok(RestartStrategy::Fixed),
+        "luby" => Ok(RestartStrategy::Luby),
+        "glucose" => Ok(RestartStrategy::Glucose),
+        value => Err(format!("'{value}' is not a valid restart strategy.")),
+    }
+}
+
+fn priority_weight_parser(s: &str) -> Result<f64, String> {
+    s.parse::<f64>()
+        .ok()
+        .filter(|weight| weight.is_finite() && *weight > 0.0)
+        .ok_or(format!("'{s}' is not a valid priority weight, it should be positive."))
+}
+
+fn time_limit_parser(s: &str) -> Result<Duration, String> {
+    s.parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or(format!("'{s}' is not a valid time limit in seconds."))
+}
+
 fn upper_bound_encoding_parser(s: &str) -> Result<UpperBoundEncoding, String> {
     match s {
         "gte" => Ok(UpperBoundEncoding::GTE),
@@ -273,3 +479,24 @@ impl std::fmt::Display for UpperBoundEncoding {
         }
     }
 }
+
+impl std::fmt::Display for OptimisationStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OptimisationStrategy::LinearSearch => write!(f, "linear"),
+            OptimisationStrategy::BinarySearch => write!(f, "binary"),
+            OptimisationStrategy::ProgressionSearch => write!(f, "progression"),
+            OptimisationStrategy::CoreGuided => write!(f, "core-guided"),
+        }
+    }
+}
+
+impl std::fmt::Display for RestartStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestartStrategy::Fixed => write!(f, "fixed"),
+            RestartStrategy::Luby => write!(f, "luby"),
+            RestartStrategy::Glucose => write!(f, "glucose"),
+        }
+    }
+}
@@ -1,10 +1,18 @@
-use std::fs;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{self, File},
+    io::{BufRead, BufReader},
+    path::Path,
+};
 
 use log::debug;
+use shared::heuristic_file::{HeuristicFile, HeuristicFileError};
 
 use crate::{
-    basic_types::{Function, Literal, PropositionalVariable},
+    basic_types::{Function, IntegerVariable, Literal, PropositionalVariable},
     engine::{ConstraintSatisfactionSolver, SATEngineDataStructures},
+    propagators::TimeTableCumulativePropagator,
+    result::{PumpkinError, PumpkinResult},
 };
 
 pub fn parse_cnf(
@@ -106,8 +114,19 @@ pub fn parse_wcnf(
     debug!("Top weight: {}", top_weight);
 
     let mut num_clauses_read = 0;
+    let mut resource_profiles: BTreeMap<u64, Vec<CapacityConstraintLine>> = BTreeMap::new();
     //read clauses one by one
     for line in lines {
+        //capacity constraints are not clauses, they are collected per resource and added as propagators at the end
+        if line.starts_with('r') {
+            let capacity_constraint = parse_capacity_constraint_line(line);
+            resource_profiles
+                .entry(capacity_constraint.resource)
+                .or_default()
+                .push(capacity_constraint);
+            continue;
+        }
+
         let mut raw_integers = line
             .split_whitespace()
             .filter_map(|s| {
@@ -204,5 +223,395 @@ pub fn parse_wcnf(
         num_clauses == num_clauses_read,
         "Num of clauses in the file does not match the header."
     );
+
+    debug!("Number of resources: {}", resource_profiles.len());
+    add_time_table_propagators(resource_profiles, &variables, csp_solver);
+
     Ok(objective_function)
 }
+
+//a capacity constraint states that \sum w_i * l_i <= capacity, where the l_i are literals
+//  the format is 'r [resource] [capacity] [weight_1] [literal_1] ... [weight_n] [literal_n] 0'
+//  each constraint is one time point in the profile of the resource
+//  these lines are not counted as clauses in the header
+pub struct CapacityConstraintLine {
+    pub resource: u64,
+    pub capacity: u64,
+    pub terms: Vec<(u64, i64)>, //pairs of (weight, literal) with the literal in the file numbering
+}
+
+pub fn parse_capacity_constraint_line(line: &str) -> CapacityConstraintLine {
+    let mut tokens = line.split_whitespace();
+    let mut temp = tokens.next();
+    assert!(temp == Some("r"));
+    let resource = tokens.next().unwrap().parse::<u64>().unwrap();
+    let capacity = tokens.next().unwrap().parse::<u64>().unwrap();
+
+    let mut terms = vec![];
+    loop {
+        temp = tokens.next();
+        let weight = temp
+            .expect("Capacity constraint lines must be terminated by a zero.")
+            .parse::<u64>()
+            .unwrap();
+        if weight == 0 {
+            break;
+        }
+        let literal = tokens.next().unwrap().parse::<i64>().unwrap();
+        assert!(literal != 0, "Zero values are unexpected at this point");
+        terms.push((weight, literal));
+    }
+    CapacityConstraintLine {
+        resource,
+        capacity,
+        terms,
+    }
+}
+
+fn add_time_table_propagators(
+    resource_profiles: BTreeMap<u64, Vec<CapacityConstraintLine>>,
+    variables: &[PropositionalVariable],
+    csp_solver: &mut ConstraintSatisfactionSolver,
+) {
+    //the propagators work on 0-1 integer variables, which are linked to the literals from the file
+    //  i.e., [x >= 1] <-> l, and the same integer variable is used for a literal across all resources
+    let mut literal_to_integer_variable: HashMap<i64, IntegerVariable> = HashMap::new();
+
+    for profile in resource_profiles.into_values() {
+        let mut propagator = TimeTableCumulativePropagator::new();
+        for capacity_constraint in profile {
+            let integer_variables: Vec<IntegerVariable> = capacity_constraint
+                .terms
+                .iter()
+                .map(|(_, file_literal)| {
+                    *literal_to_integer_variable
+                        .entry(*file_literal)
+                        .or_insert_with(|| {
+                            let literal = Literal::new(
+                                variables[file_literal.unsigned_abs() as usize - 1], //minus one is important since in the vector the indicies are from zero, whereas the indexing is from 1 in the file
+                                *file_literal > 0,
+                            );
+                            let integer_variable = csp_solver.create_new_integer_variable(0, 1);
+                            let lower_bound_literal =
+                                csp_solver.get_lower_bound_literal(integer_variable, 1);
+                            csp_solver.add_permanent_clause(vec![!literal, lower_bound_literal]);
+                            csp_solver.add_permanent_clause(vec![literal, !lower_bound_literal]);
+                            integer_variable
+                        })
+                })
+                .collect();
+
+            let weights: Vec<i64> = capacity_constraint
+                .terms
+                .iter()
+                .map(|(weight, _)| *weight as i64)
+                .collect();
+
+            propagator.add_time_point(
+                &integer_variables,
+                &weights,
+                capacity_constraint.capacity as i64,
+            );
+        }
+        debug!(
+            "Adding time table propagator with {} time points",
+            propagator.num_time_points()
+        );
+        csp_solver.add_propagator(Box::new(propagator));
+    }
+}
+
+//reads a variable order for the priority heuristics, given as variable indices from the highest to the lowest priority
+//  the indices may be spread over several lines, and 'c' tokens are skipped
+//  so the first line of an instance produced by older versions of the modeling tool can be used as an order file as well
+pub fn parse_variable_order_file(
+    file_location: &Path,
+    num_variables: u32,
+) -> PumpkinResult<Vec<PropositionalVariable>> {
+    let file_name = file_location.display().to_string();
+    let file_contents = fs::read_to_string(file_location)
+        .map_err(|error| PumpkinError::FileReadingError(error, file_name.clone()))?;
+    parse_variable_order(&file_contents, &file_name, num_variables)
+}
+
+//reads the variable order from the first line of the instance, which must be a comment line
+//  the modeling tool no longer writes this line, it writes a separate heuristic file instead
+//  only the first line is read, since instances can be large
+pub fn parse_variable_order_from_comment(
+    file_location: &str,
+    num_variables: u32,
+) -> PumpkinResult<Vec<PropositionalVariable>> {
+    let mut first_line = String::new();
+    BufReader::new(File::open(file_location)?).read_line(&mut first_line)?;
+    if !first_line.starts_with('c') {
+        return Err(PumpkinError::MissingVariableOrderComment(
+            file_location.to_owned(),
+        ));
+    }
+    parse_variable_order(&first_line, file_location, num_variables)
+}
+
+//the order is checked in full, since the variable selector expects every variable of the instance at most once
+fn parse_variable_order(
+    text: &str,
+    file_name: &str,
+    num_variables: u32,
+) -> PumpkinResult<Vec<PropositionalVariable>> {
+    let mut is_ordered = vec![false; num_variables as usize + 1];
+    let mut order = vec![];
+    for (line_index, line) in text.lines().enumerate() {
+        for token in line.split_whitespace().filter(|s| *s != "c") {
+            let index = match token.parse::<u32>() {
+                Ok(index) if index > 0 => index,
+                _ => {
+                    return Err(PumpkinError::InvalidVariableIndex(
+                        file_name.to_owned(),
+                        line_index + 1,
+                        token.to_owned(),
+                    ))
+                }
+            };
+            if index > num_variables {
+                return Err(PumpkinError::UnknownVariable(
+                    file_name.to_owned(),
+                    line_index + 1,
+                    index,
+                    num_variables,
+                ));
+            }
+            if is_ordered[index as usize] {
+                return Err(PumpkinError::DuplicateVariable(
+                    file_name.to_owned(),
+                    line_index + 1,
+                    index,
+                ));
+            }
+            is_ordered[index as usize] = true;
+            //the variables of the file are created first, right after the root variable at index zero
+            //  so the index in the file is also the index of the solver variable
+            order.push(PropositionalVariable::new(index));
+        }
+    }
+    Ok(order)
+}
+
+//reads the variable order and the preferred polarities from a heuristic file written by the modeling tool
+//  only the ranking of the priorities is used, and the groups are ignored
+pub fn parse_heuristic_file(
+    file_location: &Path,
+    num_variables: u32,
+) -> Result<(Vec<PropositionalVariable>, Vec<Literal>), HeuristicFileError> {
+    let heuristic = HeuristicFile::read(file_location)?;
+    heuristic.check_variables(num_variables as usize)?;
+    //as for the order file, the index in the file is also the index of the solver variable
+    let order = heuristic
+        .order()
+        .into_iter()
+        .map(|index| PropositionalVariable::new(index as u32))
+        .collect();
+    let phases = heuristic
+        .polarities()
+        .into_iter()
+        .map(|(index, polarity)| Literal::new(PropositionalVariable::new(index as u32), polarity))
+        .collect();
+    Ok((order, phases))
+}
+
+//reads the number of variables from the header of the instance, without reading the clauses
+pub fn parse_num_variables(file_location: &str) -> std::io::Result<u32> {
+    let reader = BufReader::new(File::open(file_location)?);
+    for line in reader.lines() {
+        let line = line?;
+        if line.starts_with('c') {
+            continue;
+        }
+        //the format is 'p [cnf or wcnf] [num variables] ...'
+        let mut header = line.split_whitespace();
+        if header.next() == Some("p") {
+            if let Some(Ok(num_variables)) = header.nth(1).map(|s| s.parse::<u32>()) {
+                return Ok(num_variables);
+            }
+        }
+        break;
+    }
+    Err(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        "the instance does not start with a valid header line",
+    ))
+}
+
+//reads an assignment from the 'v' lines of a file, which may leave variables unassigned
+pub fn parse_assignment(file_location: &Path, num_variables: u32) -> PumpkinResult<Vec<Literal>> {
+    let values = read_assignment(file_location, num_variables)?;
+    //as for the variable order, the index in the file is also the index of the solver variable
+    Ok(values
+        .iter()
+        .enumerate()
+        .filter_map(|(index, value)| {
+            value.map(|value| Literal::new(PropositionalVariable::new(index as u32), value))
+        })
+        .collect())
+}
+
+fn read_assignment(file_location: &Path, num_variables: u32) -> PumpkinResult<Vec<Option<bool>>> {
+    let file_name = file_location.display().to_string();
+    let file_contents = fs::read_to_string(file_location)
+        .map_err(|error| PumpkinError::FileReadingError(error, file_name.clone()))?;
+    parse_assignment_values(&file_contents, file_name, num_variables)
+}
+
+//the value of each variable of the instance, indexed by variable, given as literals like in the output of the solver
+//  or as a string of zeros and ones as in the MaxSAT evaluation format
+//  variables that are not part of the instance, such as the ones the solver adds itself, are skipped
+fn parse_assignment_values(
+    file_contents: &str,
+    file_name: String,
+    num_variables: u32,
+) -> PumpkinResult<Vec<Option<bool>>> {
+    let mut values: Vec<Option<bool>> = vec![None; num_variables as usize + 1];
+    let mut num_skipped = 0;
+    for (line_index, line) in file_contents.lines().enumerate() {
+        let mut tokens = line.split_whitespace();
+        if tokens.next() != Some("v") {
+            continue;
+        }
+        let tokens: Vec<&str> = tokens.collect();
+        let assigned: Vec<(u32, bool)> = match tokens.as_slice() {
+            [bits] if bits.len() > 1 && bits.chars().all(|c| c == '0' || c == '1') => bits
+                .chars()
+                .zip(1..)
+                .map(|(bit, index)| (index, bit == '1'))
+                .collect(),
+            literals => literals
+                .iter()
+                .map(|literal| {
+                    literal
+                        .parse::<i64>()
+                        .map(|value| (value.unsigned_abs() as u32, !literal.starts_with('-')))
+                        .map_err(|_| {
+                            PumpkinError::InvalidLiteral(
+                                file_name.clone(),
+                                line_index + 1,
+                                literal.to_string(),
+                            )
+                        })
+                })
+                .collect::<PumpkinResult<_>>()?,
+        };
+        for (index, value) in assigned {
+            //the solver prints its root variable as the literal zero
+            if index == 0 {
+                continue;
+            }
+            if index > num_variables {
+                num_skipped += 1;
+                continue;
+            }
+            match values[index as usize] {
+                Some(previous) if previous != value => {
+                    return Err(PumpkinError::ContradictoryAssignment(file_name, index))
+                }
+                _ => values[index as usize] = Some(value),
+            }
+        }
+    }
+    if num_skipped > 0 {
+        debug!(
+            "Skipped {} literals of variables that are not in the instance.",
+            num_skipped
+        );
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order_indices(text: &str, num_variables: u32) -> PumpkinResult<Vec<u32>> {
+        parse_variable_order(text, "test", num_variables)
+            .map(|order| order.iter().map(|variable| variable.index()).collect())
+    }
+
+    #[test]
+    fn test_assignment_as_literals_or_bits() {
+        let expected = vec![None, Some(false), Some(true), Some(true), Some(false)];
+        let from_literals = "c comment\nv -1 2\nv 3 -4 0\n";
+        assert_eq!(
+            parse_assignment_values(from_literals, "test".to_owned(), 4).unwrap(),
+            expected
+        );
+        assert_eq!(
+            parse_assignment_values("v 0110", "test".to_owned(), 4).unwrap(),
+            expected
+        );
+        //a single digit is a literal, not a string of bits
+        assert_eq!(
+            parse_assignment_values("v 1", "test".to_owned(), 4).unwrap(),
+            vec![None, Some(true), None, None, None]
+        );
+    }
+
+    #[test]
+    fn test_assignment_skips_variables_outside_the_instance() {
+        //the solver prints its root variable as the literal zero, and the variables it added after the instance
+        assert_eq!(
+            parse_assignment_values("v -0 1 -2 3 -4 5", "test".to_owned(), 2).unwrap(),
+            vec![None, Some(true), Some(false)]
+        );
+        assert_eq!(
+            parse_assignment_values("v 10110", "test".to_owned(), 2).unwrap(),
+            vec![None, Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn test_invalid_assignment_is_reported() {
+        assert!(matches!(
+            parse_assignment_values("v 1 -2\nv 3 x", "test".to_owned(), 4),
+            Err(PumpkinError::InvalidLiteral(_, 2, token)) if token == "x"
+        ));
+        assert!(matches!(
+            parse_assignment_values("v 1 -2\nv 3 -1", "test".to_owned(), 4),
+            Err(PumpkinError::ContradictoryAssignment(_, 1))
+        ));
+        //repeating a literal with the same value is fine
+        assert!(parse_assignment_values("v 1 -2 1", "test".to_owned(), 4).is_ok());
+    }
+
+    #[test]
+    fn test_variable_order_over_several_lines() {
+        assert_eq!(
+            order_indices("c 3 1\n4\n\n2 c", 4).unwrap(),
+            vec![3, 1, 4, 2]
+        );
+    }
+
+    #[test]
+    fn test_invalid_variable_order_is_reported() {
+        assert!(matches!(
+            order_indices("3 1\n4 x 2", 4),
+            Err(PumpkinError::InvalidVariableIndex(_, 2, token)) if token == "x"
+        ));
+        assert!(matches!(
+            order_indices("3 0", 4),
+            Err(PumpkinError::InvalidVariableIndex(_, 1, token)) if token == "0"
+        ));
+        assert!(matches!(
+            order_indices("3 3 2", 4),
+            Err(PumpkinError::DuplicateVariable(_, 1, 3))
+        ));
+        assert!(matches!(
+            order_indices("3\n99999", 4),
+            Err(PumpkinError::UnknownVariable(_, 2, 99999, 4))
+        ));
+        //an ordinary comment is not mistaken for a variable order
+        assert_eq!(
+            order_indices("c generated by the modeling tool", 4)
+                .unwrap_err()
+                .to_string(),
+            "test:1: 'generated' is not a valid variable index, indices start from one"
+        );
+    }
+}
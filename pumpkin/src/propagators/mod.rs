@@ -1,7 +1,9 @@
 mod clausal_propagator;
 mod constraint_programming_propagator;
 mod simple_linear_inequality_propagator;
+mod time_table_cumulative_propagator;
 
 pub use clausal_propagator::ClausalPropagator;
 pub use constraint_programming_propagator::ConstraintProgrammingPropagator;
 pub use simple_linear_inequality_propagator::SimpleLinearInequalityPropagator;
+pub use time_table_cumulative_propagator::TimeTableCumulativePropagator;
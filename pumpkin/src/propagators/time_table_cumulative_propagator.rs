@@ -0,0 +1,419 @@
+use crate::{
+    basic_types::{
+        EnqueueStatus, IntegerVariable, Predicate, PropagationStatusCP, PropositionalConjunction,
+    },
+    engine::DomainManager,
+    pumpkin_asserts::{pumpkin_assert_moderate, pumpkin_assert_simple},
+};
+
+use super::ConstraintProgrammingPropagator;
+
+//time-table propagator for the profile of a single renewable resource
+//  the profile consists of time points, and for each time point t the constraint \sum w_i * x_i <= c_t must hold
+//  each x_i is a 0-1 variable that indicates that a task uses the resource at time point t
+//the propagator keeps track of the compulsory usage at each time point, i.e., the sum of the weights of the variables set to one
+//  whenever the usage exceeds the capacity a conflict is reported
+//  and whenever a task no longer fits on top of the usage, its variable is set to zero
+pub struct TimeTableCumulativePropagator {
+    time_points: Vec<TimePoint>,
+    occurrences: Vec<Vec<Occurrence>>, //[var_id] lists the time points the integer variable with id 'var_id' takes part in
+    counted_variables: Vec<IntegerVariable>, //variables set to one that are included in the usage, in the order they were counted
+    dirty_time_points: Vec<usize>,
+    is_dirty: Vec<bool>,
+    propagation_reasons: Vec<PropositionalConjunction>, //[var_id] = c, where c is the reason for setting the variable with id 'var_id' to zero
+}
+
+struct TimePoint {
+    terms: Vec<Term>,
+    capacity: i64,
+    usage: i64,
+    max_weight: i64,
+}
+
+struct Term {
+    weight: i64,
+    integer_variable: IntegerVariable,
+}
+
+#[derive(Clone, Copy)]
+struct Occurrence {
+    time_point: usize,
+    weight: i64,
+}
+
+impl TimeTableCumulativePropagator {
+    pub fn new() -> TimeTableCumulativePropagator {
+        TimeTableCumulativePropagator {
+            time_points: vec![],
+            occurrences: vec![],
+            counted_variables: vec![],
+            dirty_time_points: vec![],
+            is_dirty: vec![],
+            propagation_reasons: vec![],
+        }
+    }
+
+    //adds the constraint \sum w_i * x_i <= capacity for a time point of the profile
+    //  the integer variables are expected to have the domain [0, 1]
+    pub fn add_time_point(
+        &mut self,
+        integer_variables: &[IntegerVariable],
+        weights: &[i64],
+        capacity: i64,
+    ) {
+        pumpkin_assert_simple!(integer_variables.len() == weights.len());
+        pumpkin_assert_simple!(weights.iter().all(|w| *w >= 0));
+
+        let terms: Vec<Term> = integer_variables
+            .iter()
+            .zip(weights.iter())
+            .filter(|x| *x.1 > 0)
+            .map(|x| Term {
+                weight: *x.1,
+                integer_variable: *x.0,
+            })
+            .collect();
+
+        let time_point = self.time_points.len();
+        for term in &terms {
+            let id = term.integer_variable.id as usize;
+            if id >= self.occurrences.len() {
+                self.occurrences.resize(id + 1, vec![]);
+            }
+            self.occurrences[id].push(Occurrence {
+                time_point,
+                weight: term.weight,
+            });
+        }
+
+        self.time_points.push(TimePoint {
+            max_weight: terms.iter().map(|t| t.weight).max().unwrap_or(0),
+            terms,
+            capacity,
+            usage: 0,
+        });
+        self.is_dirty.push(false);
+    }
+
+    pub fn num_time_points(&self) -> usize {
+        self.time_points.len()
+    }
+
+    fn mark_dirty(&mut self, time_point: usize) {
+        if !self.is_dirty[time_point] {
+            self.is_dirty[time_point] = true;
+            self.dirty_time_points.push(time_point);
+        }
+    }
+
+    //the reason for the current usage at the time point are the variables that are set to one
+    fn create_reason(time_point: &TimePoint, domains: &DomainManager) -> PropositionalConjunction {
+        time_point
+            .terms
+            .iter()
+            .filter(|t| domains.get_lower_bound(t.integer_variable) == 1)
+            .map(|t| domains.get_lower_bound_predicate(t.integer_variable))
+            .collect::<Vec<Predicate>>()
+            .into()
+    }
+
+    fn compute_usage_from_scratch(time_point: &TimePoint, domains: &DomainManager) -> i64 {
+        time_point
+            .terms
+            .iter()
+            .filter(|t| domains.get_lower_bound(t.integer_variable) == 1)
+            .map(|t| t.weight)
+            .sum()
+    }
+}
+
+impl Default for TimeTableCumulativePropagator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConstraintProgrammingPropagator for TimeTableCumulativePropagator {
+    fn propagate(&mut self, domains: &mut DomainManager) -> PropagationStatusCP {
+        while let Some(time_point_index) = self.dirty_time_points.pop() {
+            self.is_dirty[time_point_index] = false;
+            let time_point = &self.time_points[time_point_index];
+
+            pumpkin_assert_moderate!(
+                time_point.usage
+                    == TimeTableCumulativePropagator::compute_usage_from_scratch(
+                        time_point, domains
+                    )
+            );
+
+            if time_point.usage > time_point.capacity {
+                return PropagationStatusCP::ConflictDetected {
+                    failure_reason: TimeTableCumulativePropagator::create_reason(
+                        time_point, domains,
+                    ),
+                };
+            }
+
+            if time_point.usage + time_point.max_weight <= time_point.capacity {
+                continue;
+            }
+
+            //the reason is only computed once it is clear that at least one variable will be propagated
+            let mut reason: Option<PropositionalConjunction> = None;
+            for term in &time_point.terms {
+                if domains.is_integer_variable_assigned(term.integer_variable)
+                    || time_point.usage + term.weight <= time_point.capacity
+                {
+                    continue;
+                }
+
+                domains.tighten_upper_bound(term.integer_variable, 0);
+                self.propagation_reasons[term.integer_variable] = reason
+                    .get_or_insert_with(|| {
+                        TimeTableCumulativePropagator::create_reason(time_point, domains)
+                    })
+                    .clone();
+            }
+        }
+        PropagationStatusCP::NoConflictDetected
+    }
+
+    fn debug_propagate_from_scratch(&self, domains: &mut DomainManager) -> PropagationStatusCP {
+        for time_point in &self.time_points {
+            let usage =
+                TimeTableCumulativePropagator::compute_usage_from_scratch(time_point, domains);
+
+            if usage > time_point.capacity {
+                return PropagationStatusCP::ConflictDetected {
+                    failure_reason: TimeTableCumulativePropagator::create_reason(
+                        time_point, domains,
+                    ),
+                };
+            }
+
+            for term in &time_point.terms {
+                if !domains.is_integer_variable_assigned(term.integer_variable)
+                    && usage + term.weight > time_point.capacity
+                {
+                    domains.tighten_upper_bound(term.integer_variable, 0);
+                }
+            }
+        }
+        PropagationStatusCP::NoConflictDetected
+    }
+
+    fn synchronise(&mut self, domains: &DomainManager) {
+        //backtracking undoes the most recent assignments, so the variables that are no longer set to one are at the top of the stack
+        while let Some(integer_variable) = self.counted_variables.last() {
+            if domains.get_lower_bound(*integer_variable) == 1 {
+                break;
+            }
+            for occurrence in &self.occurrences[*integer_variable] {
+                self.time_points[occurrence.time_point].usage -= occurrence.weight;
+            }
+            self.counted_variables.pop();
+        }
+
+        //the propagator was at a fixed point before the backtracked decisions were made
+        for time_point in self.dirty_time_points.drain(..) {
+            self.is_dirty[time_point] = false;
+        }
+    }
+
+    fn notify_lower_bound_integer_variable_change(
+        &mut self,
+        integer_variable: IntegerVariable,
+        _old_lower_bound: i32,
+        _new_lower_bound: i32,
+        _domains: &DomainManager,
+    ) -> EnqueueStatus {
+        self.counted_variables.push(integer_variable);
+
+        let mut should_enqueue = false;
+        for index in 0..self.occurrences[integer_variable].len() {
+            let occurrence = self.occurrences[integer_variable][index];
+            let time_point = &mut self.time_points[occurrence.time_point];
+            time_point.usage += occurrence.weight;
+            //only enqueue if the time point may either be overloaded or propagate
+            if time_point.usage + time_point.max_weight > time_point.capacity {
+                should_enqueue = true;
+                self.mark_dirty(occurrence.time_point);
+            }
+        }
+
+        if should_enqueue {
+            EnqueueStatus::ShouldEnqueue
+        } else {
+            EnqueueStatus::DoNotEnqueue
+        }
+    }
+
+    fn notify_upper_bound_integer_variable_change(
+        &mut self,
+        _integer_variable: IntegerVariable,
+        _old_upper_bound: i32,
+        _new_upper_bound: i32,
+        _domains: &DomainManager,
+    ) -> EnqueueStatus {
+        panic!("This propagator should not be subscribed to upper bound changes!");
+    }
+
+    fn notify_domain_hole_integer_variable_change(
+        &mut self,
+        _integer_variable: IntegerVariable,
+        _removed_value_from_domain: i32,
+        _domains: &DomainManager,
+    ) -> EnqueueStatus {
+        panic!("This propagator should not be subscribed to domain hole changes!");
+    }
+
+    fn get_reason_for_propagation(&mut self, predicate: Predicate) -> PropositionalConjunction {
+        //the only propagations done are setting variables to zero
+        pumpkin_assert_moderate!(predicate.get_right_hand_side() == 0);
+        self.propagation_reasons[predicate.get_integer_variable()].clone()
+    }
+
+    fn priority(&self) -> u32 {
+        2
+    }
+
+    fn initialise_at_root(&mut self, domains: &mut DomainManager) -> PropagationStatusCP {
+        let max_integer_id = self.occurrences.len();
+        self.propagation_reasons
+            .resize(max_integer_id, PropositionalConjunction::new());
+
+        //variables may already have been fixed at the root, so the usage is computed from scratch
+        self.counted_variables.clear();
+        for time_point in self.time_points.iter_mut() {
+            time_point.usage = 0;
+        }
+        for id in 0..max_integer_id {
+            let integer_variable = IntegerVariable { id: id as u32 };
+            if self.occurrences[id].is_empty() || domains.get_lower_bound(integer_variable) == 0 {
+                continue;
+            }
+            self.counted_variables.push(integer_variable);
+            for occurrence in &self.occurrences[id] {
+                self.time_points[occurrence.time_point].usage += occurrence.weight;
+            }
+        }
+
+        self.dirty_time_points.clear();
+        for time_point in 0..self.time_points.len() {
+            self.is_dirty[time_point] = false;
+            self.mark_dirty(time_point);
+        }
+
+        self.propagate(domains)
+    }
+
+    fn name(&self) -> &str {
+        "Time Table Cumulative Propagator"
+    }
+
+    fn get_integer_variables_to_watch_for_lower_bound_changes(&self) -> Vec<IntegerVariable> {
+        //only variables being set to one change the usage of the resource
+        (0..self.occurrences.len())
+            .filter(|id| !self.occurrences[*id].is_empty())
+            .map(|id| IntegerVariable { id: id as u32 })
+            .collect()
+    }
+
+    fn get_integer_variables_to_watch_for_upper_bound_changes(&self) -> Vec<IntegerVariable> {
+        vec![]
+    }
+
+    fn get_integer_variables_to_watch_for_domain_hole_changes(&self) -> Vec<IntegerVariable> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        basic_types::{CSPSolverExecutionFlag, ClauseAdditionOutcome, Literal},
+        engine::ConstraintSatisfactionSolver,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_tasks_that_no_longer_fit_are_excluded() {
+        let mut csp_solver = ConstraintSatisfactionSolver::default();
+        let xs = create_variables(&mut csp_solver, 3);
+        let mut propagator = TimeTableCumulativePropagator::new();
+        propagator.add_time_point(&xs, &[2, 2, 1], 3);
+        csp_solver.add_propagator(Box::new(propagator));
+
+        let literals = lower_bound_literals(&csp_solver, &xs);
+        assert_eq!(
+            ClauseAdditionOutcome::NoConflictDetected,
+            csp_solver.add_unit_clause(literals[0])
+        );
+
+        let assignments = csp_solver.get_propositional_assignments();
+        assert!(assignments.is_literal_assigned_false(literals[1]));
+        assert!(assignments.is_literal_unassigned(literals[2]));
+
+        assert_eq!(
+            ClauseAdditionOutcome::Infeasible,
+            csp_solver.add_unit_clause(literals[1])
+        );
+    }
+
+    #[test]
+    fn test_solution_respects_capacity() {
+        let mut csp_solver = ConstraintSatisfactionSolver::default();
+        let xs = create_variables(&mut csp_solver, 3);
+        let mut propagator = TimeTableCumulativePropagator::new();
+        propagator.add_time_point(&xs, &[2, 2, 1], 3);
+        propagator.add_time_point(&xs[1..], &[2, 2], 3);
+        csp_solver.add_propagator(Box::new(propagator));
+
+        let literals = lower_bound_literals(&csp_solver, &xs);
+        csp_solver.add_permanent_clause(vec![literals[0], literals[1]]);
+        csp_solver.add_permanent_clause(vec![literals[1], literals[2]]);
+
+        assert!(matches!(
+            csp_solver.solve(i64::MAX),
+            CSPSolverExecutionFlag::Feasible
+        ));
+        //both {x1} and {x0, x2} are solutions, so only the constraints themselves are checked
+        let assignments = csp_solver.get_propositional_assignments();
+        let usage = |terms: &[(usize, i64)]| -> i64 {
+            terms
+                .iter()
+                .filter(|(index, _)| assignments.is_literal_assigned_true(literals[*index]))
+                .map(|(_, weight)| weight)
+                .sum()
+        };
+        assert!(usage(&[(0, 2), (1, 2), (2, 1)]) <= 3);
+        assert!(usage(&[(1, 2), (2, 2)]) <= 3);
+        assert!(
+            assignments.is_literal_assigned_true(literals[0])
+                || assignments.is_literal_assigned_true(literals[1])
+        );
+        assert!(
+            assignments.is_literal_assigned_true(literals[1])
+                || assignments.is_literal_assigned_true(literals[2])
+        );
+    }
+
+    fn create_variables(
+        csp_solver: &mut ConstraintSatisfactionSolver,
+        n: usize,
+    ) -> Vec<IntegerVariable> {
+        (0..n)
+            .map(|_| csp_solver.create_new_integer_variable(0, 1))
+            .collect()
+    }
+
+    fn lower_bound_literals(
+        csp_solver: &ConstraintSatisfactionSolver,
+        xs: &[IntegerVariable],
+    ) -> Vec<Literal> {
+        xs.iter()
+            .map(|x| csp_solver.get_lower_bound_literal(*x, 1))
+            .collect()
+    }
+}
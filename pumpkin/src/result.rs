@@ -1,3 +1,4 @@
+use shared::heuristic_file::HeuristicFileError;
 use thiserror::Error;
 
 pub type PumpkinResult<T> = Result<T, PumpkinError>;
@@ -16,5 +17,25 @@ pub enum PumpkinError {
     InvalidInstanceFile,
     #[error("No file location given")]
     MissingFileError,
+    #[error("Invalid heuristic file, more details: {0}")]
+    InvalidHeuristicFile(#[from] HeuristicFileError),
+    #[error("{0}:{1}: '{2}' is not a valid variable index, indices start from one")]
+    InvalidVariableIndex(String, usize, String),
+    #[error("{0}:{1}: variable {2} is not part of the instance, which has {3} variables")]
+    UnknownVariable(String, usize, u32, u32),
+    #[error("{0}:{1}: variable {2} appears in the variable order more than once")]
+    DuplicateVariable(String, usize, u32),
+    #[error("The first line of {0} is not a comment line with the variable order")]
+    MissingVariableOrderComment(String),
+    #[error("{0}:{1}: '{2}' is not a valid literal")]
+    InvalidLiteral(String, usize, String),
+    #[error("{0} assigns both values to variable {1}")]
+    ContradictoryAssignment(String, u32),
+    #[error("The initial solution does not assign variable {0}, and propagating it does not either")]
+    IncompleteAssignment(u32),
+    #[error("The initial solution violates the hard clauses, or propagating it leads to a conflict")]
+    InfeasibleInitialSolution,
+    #[error("The solver was stopped while completing the initial solution")]
+    InitialSolutionTimeout,
 }
 
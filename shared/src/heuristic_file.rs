@@ -0,0 +1,411 @@
+//! The heuristic file that is written next to a generated instance. It tells a solver which
+//! variables to branch on first and which value to try, without touching the instance itself.
+//!
+//! The format is line based, tokens are separated by whitespace and line numbers start at 1:
+//!
+//! ```text
+//! c a comment, blank lines are skipped as well
+//! g [group] [key]=[value] ...
+//! v [variable] [priority] [polarity] [group]
+//! ```
+//!
+//! A `g` line declares a group of variables with free-form metadata, such as the segment the
+//! variables belong to. A `v` line gives the hint for one variable: variables with a higher
+//! priority are branched on first, the polarity is `+` or `-` for the value to try first or `?`
+//! for no preference, and the group is optional.
+
+use std::{
+    collections::HashSet,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// Why a heuristic file could not be read
+#[derive(Error, Debug)]
+pub enum HeuristicFileError {
+    #[error("Failed to read file {file}, more details: {source}")]
+    Io {
+        file: String,
+        source: std::io::Error,
+    },
+    #[error("{file}:{line}: unknown line type '{token}', expected 'c', 'g' or 'v'")]
+    UnknownLine {
+        file: String,
+        line: usize,
+        token: String,
+    },
+    #[error("{file}:{line}: missing field '{field}'")]
+    MissingField {
+        file: String,
+        line: usize,
+        field: &'static str,
+    },
+    #[error("{file}:{line}: '{token}' is not a valid {field}")]
+    InvalidField {
+        file: String,
+        line: usize,
+        field: &'static str,
+        token: String,
+    },
+    #[error("{file}:{line}: unexpected '{token}' at the end of the line")]
+    TrailingToken {
+        file: String,
+        line: usize,
+        token: String,
+    },
+    #[error("{file}:{line}: variable {variable} already has a hint")]
+    DuplicateVariable {
+        file: String,
+        line: usize,
+        variable: usize,
+    },
+    #[error("{file}:{line}: group {group} is declared twice")]
+    DuplicateGroup {
+        file: String,
+        line: usize,
+        group: usize,
+    },
+    #[error("{file}:{line}: group {group} is not declared")]
+    UnknownGroup {
+        file: String,
+        line: usize,
+        group: usize,
+    },
+    #[error("The heuristic file has a hint for variable {variable}, but the instance only has {variables} variables")]
+    UnknownVariable { variable: usize, variables: usize },
+}
+
+/// The branching hint for a single variable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariableHint {
+    /// The variable as numbered in the instance, starting from 1
+    pub variable: usize,
+    /// Variables with a higher priority are branched on first
+    pub priority: u64,
+    /// The value to try first, if there is a preference
+    pub polarity: Option<bool>,
+    pub group: Option<usize>,
+}
+
+/// Metadata shared by a set of variables, e.g. the segment whose start times they encode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariableGroup {
+    pub id: usize,
+    /// Key and value pairs, neither of which may contain whitespace or '='
+    pub metadata: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeuristicFile {
+    pub groups: Vec<VariableGroup>,
+    pub variables: Vec<VariableHint>,
+}
+
+impl HeuristicFile {
+    /// The heuristic file of '2Fj301_1.wcnf' is '2Fj301_1.heur'
+    pub fn path_for(wcnf: &Path) -> PathBuf {
+        wcnf.with_extension("heur")
+    }
+
+    pub fn read(path: &Path) -> Result<Self, HeuristicFileError> {
+        let file = path.display().to_string();
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents, &file),
+            Err(source) => Err(HeuristicFileError::Io { file, source }),
+        }
+    }
+
+    /// Parses the contents of a heuristic file, `file` is only used in the errors
+    pub fn parse(contents: &str, file: &str) -> Result<Self, HeuristicFileError> {
+        let mut heuristic = HeuristicFile::default();
+        // Groups may be used before they are declared, so they are only checked at the end
+        let mut groups: HashSet<usize> = HashSet::new();
+        let mut variables: HashSet<usize> = HashSet::new();
+        let mut hint_lines: Vec<usize> = Vec::new();
+        for (index, text) in contents.lines().enumerate() {
+            let line = index + 1;
+            let mut tokens = LineTokens {
+                tokens: text.split_whitespace(),
+                file,
+                line,
+            };
+            match tokens.tokens.next() {
+                None => continue,
+                // A comment does not need a space after the 'c'
+                Some(token) if token.starts_with('c') => continue,
+                Some("g") => {
+                    let id = tokens.parse_next("group")?;
+                    if !groups.insert(id) {
+                        return Err(HeuristicFileError::DuplicateGroup {
+                            file: file.to_owned(),
+                            line,
+                            group: id,
+                        });
+                    }
+                    let metadata = tokens
+                        .tokens
+                        .map(|token| match token.split_once('=') {
+                            Some((key, value)) if !key.is_empty() && !value.contains('=') => {
+                                Ok((key.to_owned(), value.to_owned()))
+                            }
+                            _ => Err(invalid_field(file, line, "metadata", token)),
+                        })
+                        .collect::<Result<_, _>>()?;
+                    heuristic.groups.push(VariableGroup { id, metadata });
+                }
+                Some("v") => {
+                    let variable = tokens.parse_next("variable")?;
+                    if variable == 0 {
+                        return Err(invalid_field(file, line, "variable", "0"));
+                    }
+                    if !variables.insert(variable) {
+                        return Err(HeuristicFileError::DuplicateVariable {
+                            file: file.to_owned(),
+                            line,
+                            variable,
+                        });
+                    }
+                    let priority = tokens.parse_next("priority")?;
+                    let polarity = match tokens.next("polarity")? {
+                        "+" => Some(true),
+                        "-" => Some(false),
+                        "?" => None,
+                        token => return Err(invalid_field(file, line, "polarity", token)),
+                    };
+                    let group = match tokens.tokens.next() {
+                        Some(token) => Some(
+                            token
+                                .parse()
+                                .map_err(|_| invalid_field(file, line, "group", token))?,
+                        ),
+                        None => None,
+                    };
+                    if let Some(token) = tokens.tokens.next() {
+                        return Err(HeuristicFileError::TrailingToken {
+                            file: file.to_owned(),
+                            line,
+                            token: token.to_owned(),
+                        });
+                    }
+                    heuristic.variables.push(VariableHint {
+                        variable,
+                        priority,
+                        polarity,
+                        group,
+                    });
+                    hint_lines.push(line);
+                }
+                Some(token) => {
+                    return Err(HeuristicFileError::UnknownLine {
+                        file: file.to_owned(),
+                        line,
+                        token: token.to_owned(),
+                    })
+                }
+            }
+        }
+        for (hint, line) in heuristic.variables.iter().zip(hint_lines) {
+            if let Some(group) = hint.group.filter(|group| !groups.contains(group)) {
+                return Err(HeuristicFileError::UnknownGroup {
+                    file: file.to_owned(),
+                    line,
+                    group,
+                });
+            }
+        }
+        Ok(heuristic)
+    }
+
+    pub fn write_to_string(&self) -> String {
+        let mut output = String::new();
+        for group in self.groups.iter() {
+            write!(output, "g {}", group.id).unwrap();
+            for (key, value) in group.metadata.iter() {
+                write!(output, " {}={}", key, value).unwrap();
+            }
+            output.push('\n');
+        }
+        for hint in self.variables.iter() {
+            let polarity = match hint.polarity {
+                Some(true) => "+",
+                Some(false) => "-",
+                None => "?",
+            };
+            write!(output, "v {} {} {}", hint.variable, hint.priority, polarity).unwrap();
+            if let Some(group) = hint.group {
+                write!(output, " {}", group).unwrap();
+            }
+            output.push('\n');
+        }
+        output
+    }
+
+    /// The variables from the highest to the lowest priority, variables with the same priority
+    /// keep the order of the file
+    pub fn order(&self) -> Vec<usize> {
+        let mut hints: Vec<&VariableHint> = self.variables.iter().collect();
+        hints.sort_by_key(|hint| std::cmp::Reverse(hint.priority));
+        hints.iter().map(|hint| hint.variable).collect()
+    }
+
+    /// The variables with a preferred polarity, together with that polarity
+    pub fn polarities(&self) -> Vec<(usize, bool)> {
+        self.variables
+            .iter()
+            .filter_map(|hint| Some((hint.variable, hint.polarity?)))
+            .collect()
+    }
+
+    /// Checks that every hint refers to one of the `variables` variables of the instance
+    pub fn check_variables(&self, variables: usize) -> Result<(), HeuristicFileError> {
+        match self.variables.iter().find(|hint| hint.variable > variables) {
+            Some(hint) => Err(HeuristicFileError::UnknownVariable {
+                variable: hint.variable,
+                variables,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+struct LineTokens<'a> {
+    tokens: std::str::SplitWhitespace<'a>,
+    file: &'a str,
+    line: usize,
+}
+
+impl<'a> LineTokens<'a> {
+    fn next(&mut self, field: &'static str) -> Result<&'a str, HeuristicFileError> {
+        self.tokens
+            .next()
+            .ok_or_else(|| HeuristicFileError::MissingField {
+                file: self.file.to_owned(),
+                line: self.line,
+                field,
+            })
+    }
+
+    fn parse_next<T: std::str::FromStr>(
+        &mut self,
+        field: &'static str,
+    ) -> Result<T, HeuristicFileError> {
+        let token = self.next(field)?;
+        token
+            .parse()
+            .map_err(|_| invalid_field(self.file, self.line, field, token))
+    }
+}
+
+fn invalid_field(file: &str, line: usize, field: &'static str, token: &str) -> HeuristicFileError {
+    HeuristicFileError::InvalidField {
+        file: file.to_owned(),
+        line,
+        field,
+        token: token.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeuristicFile, HeuristicFileError, VariableGroup, VariableHint};
+
+    fn example() -> HeuristicFile {
+        HeuristicFile {
+            groups: vec![VariableGroup {
+                id: 0,
+                metadata: vec![
+                    ("segment".to_owned(), "3".to_owned()),
+                    ("duration".to_owned(), "5".to_owned()),
+                ],
+            }],
+            variables: vec![
+                VariableHint {
+                    variable: 4,
+                    priority: 1,
+                    polarity: None,
+                    group: None,
+                },
+                VariableHint {
+                    variable: 2,
+                    priority: 7,
+                    polarity: Some(true),
+                    group: Some(0),
+                },
+                VariableHint {
+                    variable: 1,
+                    priority: 7,
+                    polarity: Some(false),
+                    group: Some(0),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let heuristic = example();
+        let text = heuristic.write_to_string();
+        assert_eq!(
+            text,
+            "g 0 segment=3 duration=5\nv 4 1 ?\nv 2 7 + 0\nv 1 7 - 0\n"
+        );
+        let parsed = HeuristicFile::parse(&("c written by hand\n\n".to_owned() + &text), "test");
+        assert_eq!(parsed.unwrap(), heuristic);
+        assert_eq!(heuristic.order(), vec![2, 1, 4]);
+        assert_eq!(heuristic.polarities(), vec![(2, true), (1, false)]);
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        let error = |contents: &str| HeuristicFile::parse(contents, "test").unwrap_err();
+        assert!(matches!(
+            error("v 1 2 +\nv 3 x +"),
+            HeuristicFileError::InvalidField {
+                line: 2,
+                field: "priority",
+                ..
+            }
+        ));
+        assert!(matches!(
+            error("v 1 2"),
+            HeuristicFileError::MissingField {
+                line: 1,
+                field: "polarity",
+                ..
+            }
+        ));
+        assert!(matches!(
+            error("v 1 2 +\nv 1 3 -"),
+            HeuristicFileError::DuplicateVariable {
+                line: 2,
+                variable: 1,
+                ..
+            }
+        ));
+        assert!(matches!(
+            error("v 1 2 + 4"),
+            HeuristicFileError::UnknownGroup {
+                line: 1,
+                group: 4,
+                ..
+            }
+        ));
+        assert!(matches!(
+            error("1 2 3 0"),
+            HeuristicFileError::UnknownLine { line: 1, .. }
+        ));
+        assert_eq!(
+            error("g 0 segment").to_string(),
+            "test:1: 'segment' is not a valid metadata"
+        );
+        assert!(matches!(
+            example().check_variables(3),
+            Err(HeuristicFileError::UnknownVariable {
+                variable: 4,
+                variables: 3
+            })
+        ));
+    }
+}
@@ -1,9 +1,11 @@
 pub mod project;
 
-pub mod floyd_warshall;
+pub mod heuristic_file;
 pub mod id_generator;
+pub mod pb_encoding;
 pub mod sat_seg_var;
 pub mod segment;
+pub mod segment_graph;
 fn main() {
     println!("Hello, world!");
 }
@@ -0,0 +1,411 @@
+use std::{collections::HashMap, collections::VecDeque, fmt::Display, str::FromStr};
+
+use crate::{id_generator::IdGenerator, sat_seg_var::Clause};
+
+/// The encodings available to turn a pseudo-boolean constraint sum(w_i * l_i) <= k into clauses.
+/// Every encoding draws its auxiliary variables from the given [IdGenerator], so after encoding
+/// the generator points at the first unused variable, as it did with the pysat encoder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PBEncoding {
+    /// Sequential weight counter (Hölldobler, Manthey and Steinke, 2012)
+    #[default]
+    SequentialWeightCounter,
+    /// Reduced BDD over the literals, sorted by decreasing weight (Eén and Sörensson, 2006)
+    Bdd,
+    /// Binary adder network followed by a lexicographic comparator (Warners, 1998)
+    Adder,
+}
+
+impl PBEncoding {
+    /// Encodes sum(weights[i] * lits[i]) <= bound. The literals follow the DIMACS convention,
+    /// i.e. a negative number is the negation of the variable.
+    pub fn encode_at_most(
+        &self,
+        lits: &[i64],
+        weights: &[usize],
+        bound: usize,
+        id_gen: &mut IdGenerator,
+    ) -> Vec<Clause> {
+        assert_eq!(lits.len(), weights.len());
+        let mut clauses: Vec<Clause> = Vec::new();
+
+        // literals that can never be true are fixed, literals with weight zero are irrelevant
+        let mut terms: Vec<(i64, usize)> = Vec::new();
+        for (&lit, &weight) in lits.iter().zip(weights.iter()) {
+            if weight > bound {
+                clauses.push(Clause::new(vec![-lit]));
+            } else if weight > 0 {
+                terms.push((lit, weight));
+            }
+        }
+        // trivially satisfied constraint, no clauses needed
+        if terms.iter().map(|(_, w)| w).sum::<usize>() <= bound {
+            return clauses;
+        }
+
+        match self {
+            PBEncoding::SequentialWeightCounter => {
+                encode_sequential_weight_counter(&terms, bound, id_gen, &mut clauses)
+            }
+            PBEncoding::Bdd => encode_bdd(&terms, bound, id_gen, &mut clauses),
+            PBEncoding::Adder => encode_adder(&terms, bound, id_gen, &mut clauses),
+        }
+        clauses
+    }
+}
+
+impl FromStr for PBEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "swc" => Ok(PBEncoding::SequentialWeightCounter),
+            "bdd" => Ok(PBEncoding::Bdd),
+            "adder" => Ok(PBEncoding::Adder),
+            value => Err(format!(
+                "'{value}' is not a valid pseudo-boolean encoding. Possible values: ['swc', 'bdd', 'adder']"
+            )),
+        }
+    }
+}
+
+impl Display for PBEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PBEncoding::SequentialWeightCounter => write!(f, "swc"),
+            PBEncoding::Bdd => write!(f, "bdd"),
+            PBEncoding::Adder => write!(f, "adder"),
+        }
+    }
+}
+
+/// Assumes every weight is in [1, bound] and that the sum of the weights exceeds the bound.
+/// counter[i][j - 1] is true if the first i + 1 terms sum to at least j.
+fn encode_sequential_weight_counter(
+    terms: &[(i64, usize)],
+    bound: usize,
+    id_gen: &mut IdGenerator,
+    clauses: &mut Vec<Clause>,
+) {
+    let num_terms = terms.len();
+    let counter: Vec<Vec<i64>> = (0..num_terms - 1)
+        .map(|_| (0..bound).map(|_| id_gen.next_id() as i64).collect())
+        .collect();
+
+    for (i, &(lit, weight)) in terms.iter().enumerate() {
+        if i < num_terms - 1 {
+            for &partial_sum in counter[i].iter().take(weight) {
+                clauses.push(Clause::new(vec![-lit, partial_sum]));
+            }
+        }
+        if i == 0 {
+            continue;
+        }
+        if i < num_terms - 1 {
+            for (&previous, &current) in counter[i - 1].iter().zip(counter[i].iter()) {
+                clauses.push(Clause::new(vec![-previous, current]));
+            }
+            for j in 0..bound - weight {
+                clauses.push(Clause::new(vec![
+                    -lit,
+                    -counter[i - 1][j],
+                    counter[i][j + weight],
+                ]));
+            }
+        }
+        // the previous terms already sum to more than bound - weight
+        clauses.push(Clause::new(vec![-lit, -counter[i - 1][bound - weight]]));
+    }
+}
+
+enum BddNode {
+    True,
+    Node(i64),
+}
+
+/// Assumes every weight is in [1, bound] and that the sum of the weights exceeds the bound.
+/// Only the implications from a node to its children are needed for an at most constraint.
+fn encode_bdd(
+    terms: &[(i64, usize)],
+    bound: usize,
+    id_gen: &mut IdGenerator,
+    clauses: &mut Vec<Clause>,
+) {
+    let mut sorted_terms = terms.to_vec();
+    sorted_terms.sort_by_key(|(_, weight)| std::cmp::Reverse(*weight));
+    // remaining_sum[i] is the largest value the terms from i onwards can sum up to
+    let mut remaining_sum = vec![0; sorted_terms.len() + 1];
+    for i in (0..sorted_terms.len()).rev() {
+        remaining_sum[i] = remaining_sum[i + 1] + sorted_terms[i].1;
+    }
+
+    let mut cache: HashMap<(usize, usize), i64> = HashMap::new();
+    if let BddNode::Node(root) = build_bdd_node(
+        0,
+        bound,
+        &sorted_terms,
+        &remaining_sum,
+        &mut cache,
+        id_gen,
+        clauses,
+    ) {
+        clauses.push(Clause::new(vec![root]));
+    }
+}
+
+fn build_bdd_node(
+    index: usize,
+    slack: usize,
+    terms: &[(i64, usize)],
+    remaining_sum: &[usize],
+    cache: &mut HashMap<(usize, usize), i64>,
+    id_gen: &mut IdGenerator,
+    clauses: &mut Vec<Clause>,
+) -> BddNode {
+    if remaining_sum[index] <= slack {
+        return BddNode::True;
+    }
+    if let Some(&node) = cache.get(&(index, slack)) {
+        return BddNode::Node(node);
+    }
+
+    let (lit, weight) = terms[index];
+    let node = id_gen.next_id() as i64;
+    // the literal is false
+    if let BddNode::Node(low) = build_bdd_node(
+        index + 1,
+        slack,
+        terms,
+        remaining_sum,
+        cache,
+        id_gen,
+        clauses,
+    ) {
+        clauses.push(Clause::new(vec![-node, low]));
+    }
+    // the literal is true
+    if weight > slack {
+        clauses.push(Clause::new(vec![-node, -lit]));
+    } else if let BddNode::Node(high) = build_bdd_node(
+        index + 1,
+        slack - weight,
+        terms,
+        remaining_sum,
+        cache,
+        id_gen,
+        clauses,
+    ) {
+        clauses.push(Clause::new(vec![-node, -lit, high]));
+    }
+
+    cache.insert((index, slack), node);
+    BddNode::Node(node)
+}
+
+/// Assumes every weight is in [1, bound] and that the sum of the weights exceeds the bound.
+fn encode_adder(
+    terms: &[(i64, usize)],
+    bound: usize,
+    id_gen: &mut IdGenerator,
+    clauses: &mut Vec<Clause>,
+) {
+    // buckets[b] holds the literals that contribute 2^b to the sum
+    let mut buckets: Vec<VecDeque<i64>> = Vec::new();
+    for &(lit, weight) in terms.iter() {
+        let mut bit = 0;
+        while weight >> bit > 0 {
+            if buckets.len() <= bit {
+                buckets.push(VecDeque::new());
+            }
+            if (weight >> bit) & 1 == 1 {
+                buckets[bit].push_back(lit);
+            }
+            bit += 1;
+        }
+    }
+
+    let mut sum_bits: Vec<Option<i64>> = Vec::new();
+    let mut bit = 0;
+    while bit < buckets.len() {
+        while buckets[bit].len() >= 2 {
+            let a = buckets[bit].pop_front().unwrap();
+            let b = buckets[bit].pop_front().unwrap();
+            let sum = id_gen.next_id() as i64;
+            let carry = id_gen.next_id() as i64;
+            if let Some(c) = buckets[bit].pop_front() {
+                full_adder(a, b, c, sum, carry, clauses);
+            } else {
+                half_adder(a, b, sum, carry, clauses);
+            }
+            buckets[bit].push_back(sum);
+            if buckets.len() <= bit + 1 {
+                buckets.push(VecDeque::new());
+            }
+            buckets[bit + 1].push_back(carry);
+        }
+        sum_bits.push(buckets[bit].pop_front());
+        bit += 1;
+    }
+
+    // forbid the sum from being lexicographically larger than the bound: whenever a bit of the
+    // sum is set where the bound has a zero, one of the higher bits set in the bound must be unset
+    let num_bits = sum_bits
+        .len()
+        .max(usize::BITS as usize - bound.leading_zeros() as usize);
+    let bound_bit = |b: usize| b < usize::BITS as usize && (bound >> b) & 1 == 1;
+    for (i, sum_bit) in sum_bits.iter().enumerate() {
+        let Some(sum_bit) = sum_bit else { continue };
+        if bound_bit(i) {
+            continue;
+        }
+        let mut clause = vec![-sum_bit];
+        let mut satisfied = false;
+        for j in i + 1..num_bits {
+            if bound_bit(j) {
+                match sum_bits.get(j).copied().flatten() {
+                    Some(higher_bit) => clause.push(-higher_bit),
+                    // the sum has a zero where the bound has a one
+                    None => satisfied = true,
+                }
+            }
+        }
+        if !satisfied {
+            clauses.push(Clause::new(clause));
+        }
+    }
+}
+
+fn half_adder(a: i64, b: i64, sum: i64, carry: i64, clauses: &mut Vec<Clause>) {
+    clauses.push(Clause::new(vec![a, b, -sum]));
+    clauses.push(Clause::new(vec![-a, -b, -sum]));
+    clauses.push(Clause::new(vec![-a, b, sum]));
+    clauses.push(Clause::new(vec![a, -b, sum]));
+
+    clauses.push(Clause::new(vec![-a, -b, carry]));
+    clauses.push(Clause::new(vec![a, -carry]));
+    clauses.push(Clause::new(vec![b, -carry]));
+}
+
+fn full_adder(a: i64, b: i64, c: i64, sum: i64, carry: i64, clauses: &mut Vec<Clause>) {
+    // sum is the parity of the inputs
+    for signs in 0..8 {
+        let lits: Vec<i64> = [a, b, c]
+            .iter()
+            .enumerate()
+            .map(|(i, &lit)| if (signs >> i) & 1 == 1 { lit } else { -lit })
+            .collect();
+        let parity = (signs as u32).count_ones() % 2 == 1;
+        clauses.push(Clause::new(vec![
+            -lits[0],
+            -lits[1],
+            -lits[2],
+            if parity { sum } else { -sum },
+        ]));
+    }
+    // carry is the majority of the inputs
+    for (x, y) in [(a, b), (a, c), (b, c)] {
+        clauses.push(Clause::new(vec![-x, -y, carry]));
+        clauses.push(Clause::new(vec![x, y, -carry]));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{id_generator::IdGenerator, sat_seg_var::Clause};
+
+    use super::PBEncoding;
+
+    fn is_satisfiable(
+        clauses: &[Clause],
+        assignment: &mut HashMap<i64, bool>,
+        free: &[i64],
+    ) -> bool {
+        let falsified = clauses.iter().any(|clause| {
+            clause.arguments().iter().all(|lit| {
+                assignment
+                    .get(&lit.abs())
+                    .is_some_and(|&value| value != (*lit > 0))
+            })
+        });
+        if falsified {
+            return false;
+        }
+        let Some((&var, rest)) = free.split_first() else {
+            return true;
+        };
+        for value in [false, true] {
+            assignment.insert(var, value);
+            if is_satisfiable(clauses, assignment, rest) {
+                assignment.remove(&var);
+                return true;
+            }
+        }
+        assignment.remove(&var);
+        false
+    }
+
+    fn check_encoding(encoding: PBEncoding, weights: &[usize], bound: usize) {
+        let lits: Vec<i64> = (1..=weights.len() as i64).collect();
+        let mut id_gen = IdGenerator::generator_for_sat();
+        id_gen.new_current(weights.len());
+        let clauses = encoding.encode_at_most(&lits, weights, bound, &mut id_gen);
+        let auxiliary: Vec<i64> = (weights.len() as i64 + 1..id_gen.0 as i64).collect();
+        assert!(clauses
+            .iter()
+            .flat_map(|c| c.arguments().iter())
+            .all(|lit| lit.unsigned_abs() < id_gen.0 as u64));
+
+        for input in 0..1usize << weights.len() {
+            let mut assignment: HashMap<i64, bool> = lits
+                .iter()
+                .enumerate()
+                .map(|(i, &lit)| (lit, (input >> i) & 1 == 1))
+                .collect();
+            let sum: usize = (0..weights.len())
+                .filter(|i| (input >> i) & 1 == 1)
+                .map(|i| weights[i])
+                .sum();
+            assert_eq!(
+                sum <= bound,
+                is_satisfiable(&clauses, &mut assignment, &auxiliary),
+                "{encoding} with weights {weights:?}, bound {bound}, input {input:b}"
+            );
+        }
+    }
+
+    #[test]
+    fn sequential_weight_counter_is_exact() {
+        for bound in 0..=9 {
+            check_encoding(PBEncoding::SequentialWeightCounter, &[1, 2, 3, 2], bound);
+        }
+        check_encoding(PBEncoding::SequentialWeightCounter, &[1, 1, 1, 1, 1], 2);
+    }
+
+    #[test]
+    fn bdd_is_exact() {
+        for bound in 0..=9 {
+            check_encoding(PBEncoding::Bdd, &[1, 2, 3, 2], bound);
+        }
+        check_encoding(PBEncoding::Bdd, &[1, 1, 1, 1, 1], 2);
+    }
+
+    #[test]
+    fn adder_is_exact() {
+        for bound in 0..=9 {
+            check_encoding(PBEncoding::Adder, &[1, 2, 3, 2], bound);
+        }
+        check_encoding(PBEncoding::Adder, &[1, 1, 1, 1, 1], 2);
+        check_encoding(PBEncoding::Adder, &[5, 4, 6], 9);
+    }
+
+    #[test]
+    fn trivial_constraints_need_no_auxiliary_variables() {
+        let mut id_gen = IdGenerator::generator_for_sat();
+        id_gen.new_current(3);
+        let clauses = PBEncoding::Bdd.encode_at_most(&[1, 2, 3], &[1, 4, 1], 3, &mut id_gen);
+        assert_eq!(clauses, vec![Clause::new(vec![-2])]);
+        assert_eq!(id_gen.next_id(), 4);
+    }
+}
@@ -1,107 +1,116 @@
-use crate::segment::Segment;
+use crate::segment_graph::SegmentGraph;
 use crate::{id_generator::IdGenerator, sat_seg_var::Clause};
 use core::fmt;
-use std::{cell::RefCell, cmp::Ordering, fmt::Display, rc::Rc};
-#[derive(Eq, Debug)]
-pub struct Project<'a> {
-    duration: usize,
+use std::{cmp::Ordering, fmt::Display};
+
+/// One way of executing a project: how long it takes and how much of each resource it uses
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Mode {
+    pub duration: usize,
+    pub resource: Vec<usize>,
+}
+
+impl Mode {
+    pub fn new(duration: usize, resource: Vec<usize>) -> Self {
+        Self { duration, resource }
+    }
+}
+
+#[derive(Eq, Debug, Clone)]
+pub struct Project {
     id: usize,
-    resource: Vec<usize>,
-    precedence: RefCell<Vec<&'a Project<'a>>>,
-    segments: Vec<Rc<RefCell<Segment>>>,
+    modes: Vec<Mode>,
+    // Ids of the projects that have to be done before this one
+    precedence: Vec<usize>,
+    // Ids of the segments in the segment graph, over all modes
+    segments: Vec<usize>,
+    // Per mode, the segments starting at a jiffy. The single segment of an empty mode starts at 0
+    by_start: Vec<Vec<Vec<usize>>>,
+    // Per mode, the segments whose last jiffy is a jiffy
+    by_end: Vec<Vec<Vec<usize>>>,
+    // Only filled in for projects with more than one mode, a single mode is always selected
+    mode_variables: Vec<usize>,
 }
 
-impl<'a> Project<'a> {
-    /// Creates a new [`Project`].
-    pub fn new(
-        duration: usize,
-        id: usize,
-        resource: Vec<usize>,
-        precedence: RefCell<Vec<&'a Project<'a>>>,
-        id_gen: &mut IdGenerator,
-    ) -> Self {
-        let segments = Project::generate_segments(id, id_gen, &resource, duration);
-        Self {
-            duration,
+impl Project {
+    /// Creates a new [`Project`] with a single mode, adding its segments to the graph.
+    pub fn new(duration: usize, id: usize, resource: Vec<usize>, graph: &mut SegmentGraph) -> Self {
+        Project::with_modes(vec![Mode::new(duration, resource)], id, graph)
+    }
+
+    /// Creates a new [`Project`] that has to be executed in exactly one of the given modes,
+    /// adding the segments of every mode to the graph.
+    pub fn with_modes(modes: Vec<Mode>, id: usize, graph: &mut SegmentGraph) -> Self {
+        assert!(!modes.is_empty(), "Project {} has no modes", id);
+        let mut project = Self {
             id,
-            resource,
-            precedence,
-            segments,
+            modes: Vec::new(),
+            precedence: Vec::new(),
+            segments: Vec::new(),
+            by_start: Vec::new(),
+            by_end: Vec::new(),
+            mode_variables: Vec::new(),
+        };
+        for mode in modes {
+            project.generate_segments(graph, mode);
         }
+        project
     }
 
-    pub fn add_presedence(&self, other: &'a Project<'a>) {
-        self.precedence.borrow_mut().push(other);
+    pub fn add_presedence(&mut self, other: usize) {
+        self.precedence.push(other);
     }
-    /// Generates the segments of a project with the following details
-    pub fn generate_segments(
-        parent_id: usize,
-        id_gen: &mut IdGenerator,
-        parent_resource: &Vec<usize>,
-        duration: usize,
-    ) -> Vec<Rc<RefCell<Segment>>> {
-        let mut segments: Vec<Rc<RefCell<Segment>>> = Vec::new();
-        //TODO: Replace this ID generation with a more distinct one
+    /// Generates the segments of a project in the given mode
+    fn generate_segments(&mut self, graph: &mut SegmentGraph, mode: Mode) {
+        let mode_index = self.modes.len();
+        let duration = mode.duration;
+        let mut by_start: Vec<Vec<usize>> = vec![Vec::new(); duration + 1];
+        let mut by_end: Vec<Vec<usize>> = vec![Vec::new(); duration + 1];
 
         if duration == 0 {
-            let segment = Segment::new(
-                0,
-                0,
-                Vec::new(),
-                id_gen.next_id(),
-                parent_id,
-                parent_resource.clone(),
-            );
-            segments.push(Rc::new(RefCell::new(segment)));
+            let id = graph.add_segment(0, 0, self.id, mode_index, mode.resource.clone());
+            by_start[0].push(id);
+            by_end[0].push(id);
+            self.segments.push(id);
         } else {
             for x in 1..duration + 1 {
                 for y in 1..duration - x + 2 {
-                    let id = id_gen.next_id();
-                    // find the precednece
+                    let id = graph.add_segment(x, y, self.id, mode_index, mode.resource.clone());
                     // rule is: if x + y of old equal x of new, then new depends on old
-                    let precedents = segments
-                        .clone()
-                        .into_iter()
-                        .filter(|old| old.borrow().start_jiff + old.borrow().og_duration == x)
-                        .collect();
-                    let resource = parent_resource.clone();
-
-                    let seg = Segment::new(x, y, precedents, id, parent_id, resource);
-                    let cell = RefCell::new(seg);
-                    segments.push(Rc::new(cell));
+                    for old in by_end[x - 1].iter() {
+                        graph.add_precedence(*old, id);
+                    }
+                    by_start[x].push(id);
+                    by_end[x + y - 1].push(id);
+                    self.segments.push(id);
                 }
             }
         }
-        segments
+        self.modes.push(mode);
+        self.by_start.push(by_start);
+        self.by_end.push(by_end);
     }
-    pub fn get_last_segments(&self) -> Vec<Rc<RefCell<Segment>>> {
-        self.segments
-            .clone()
-            .into_iter()
-            .filter(|last| last.borrow().parent_project == self.id)
-            .filter(|last| {
-                last.borrow().start_jiff + last.borrow().og_duration == self.duration + 1
-                    || self.duration == 0
-            })
+    pub fn get_last_segments(&self) -> Vec<usize> {
+        self.modes
+            .iter()
+            .zip(self.by_end.iter())
+            .flat_map(|(mode, by_end)| by_end[mode.duration].iter().copied())
             .collect()
     }
-    pub fn get_first_segments(&self) -> Vec<Rc<RefCell<Segment>>> {
-        self.segments
-            .clone()
-            .into_iter()
-            .filter(|first| first.borrow().parent_project == self.id)
-            .filter(|first| first.borrow().start_jiff <= 1)
+    pub fn get_first_segments(&self) -> Vec<usize> {
+        self.modes
+            .iter()
+            .zip(self.by_start.iter())
+            .flat_map(|(mode, by_start)| by_start[mode.duration.min(1)].iter().copied())
             .collect()
     }
-    pub fn link_with_precedents(&self) {
-        let our = self.get_first_segments();
-        for precedent in self.precedence.borrow().clone() {
-            let theirs = precedent.get_last_segments();
-            {
-                let last = &theirs;
-                let first = &our;
-                Segment::precedence_link(last, first);
-            };
+    /// Every first segment of this project can follow on every last segment of the precedent
+    pub fn link_with_precedent(&self, precedent: &Project, graph: &mut SegmentGraph) {
+        let first = self.get_first_segments();
+        for last in precedent.get_last_segments() {
+            for f in first.iter() {
+                graph.add_precedence(last, *f);
+            }
         }
     }
 
@@ -109,47 +118,87 @@ impl<'a> Project<'a> {
         self.id
     }
 
-    pub fn precedence(&self) -> &RefCell<Vec<&'a Project<'a>>> {
-        &self.precedence
+    pub fn precedence(&self) -> &[usize] {
+        self.precedence.as_ref()
     }
 
-    pub fn segments(&self) -> &[Rc<RefCell<Segment>>] {
+    pub fn segments(&self) -> &[usize] {
         self.segments.as_ref()
     }
-    pub fn generate_completion_clauses(&self) -> Vec<Clause> {
+
+    pub fn modes(&self) -> &[Mode] {
+        self.modes.as_ref()
+    }
+
+    /// The variables selecting the mode of this project, empty if there is only one mode
+    pub fn mode_variables(&self) -> &[usize] {
+        self.mode_variables.as_ref()
+    }
+
+    /// Creates one variable per mode, a project with a single mode does not need any
+    pub fn generate_mode_vars(&mut self, id_gen: &mut IdGenerator) {
+        if self.modes.len() < 2 {
+            return;
+        }
+        self.mode_variables = self.modes.iter().map(|_| id_gen.next_id()).collect();
+    }
+
+    /// Exactly one mode is selected, and a segment can only start if its mode is selected
+    pub fn generate_mode_clauses(&self, graph: &SegmentGraph) -> Vec<Clause> {
         let mut clauses: Vec<Clause> = Vec::new();
-        for jiffy in 1..self.duration + 1 {
-            //Each jiffy will produce one mega clause
-            let mut jiffy_clause: Vec<i64> = Vec::new();
-            for segment in self.get_segments_for_jiffy(jiffy) {
-                // Get the vars representing each sement
-                assert!(
-                    !segment.borrow().variables.borrow().is_empty(),
-                    "Generate segment variable has not been called on segment {:?}",
-                    segment.borrow()
-                );
-                let var_ids: Vec<i64> = Rc::clone(&segment)
-                    .borrow()
-                    .variables
-                    .borrow()
-                    .iter()
-                    .map(|x| x.id() as i64)
-                    .collect();
-                jiffy_clause.extend(var_ids);
+        if self.mode_variables.is_empty() {
+            return clauses;
+        }
+        clauses.push(Clause::new(
+            self.mode_variables.iter().map(|m| *m as i64).collect(),
+        ));
+        for (index, first) in self.mode_variables.iter().enumerate() {
+            for second in self.mode_variables.iter().skip(index + 1) {
+                clauses.push(Clause::new(vec![-(*first as i64), -(*second as i64)]));
+            }
+        }
+        for segment in self.segments.iter().map(|id| graph.segment(*id)) {
+            let mode_variable = self.mode_variables[segment.mode] as i64;
+            for var in segment.variables.iter() {
+                clauses.push(Clause::new(vec![-(var.id() as i64), mode_variable]));
             }
-            let clause = Clause::new(jiffy_clause);
-            clauses.push(clause);
         }
         clauses
     }
-    pub fn generate_last_project_segment(&self) -> Vec<Clause> {
+    pub fn generate_completion_clauses(&self, graph: &SegmentGraph) -> Vec<Clause> {
+        let mut clauses: Vec<Clause> = Vec::new();
+        for (mode_index, mode) in self.modes.iter().enumerate() {
+            for jiffy in 1..mode.duration + 1 {
+                //Each jiffy will produce one mega clause
+                let mut jiffy_clause: Vec<i64> = Vec::new();
+                // Only needs to be completed in the selected mode
+                if let Some(mode_variable) = self.mode_variables.get(mode_index) {
+                    jiffy_clause.push(-(*mode_variable as i64));
+                }
+                for segment in self.get_segments_for_jiffy(graph, mode_index, jiffy) {
+                    let segment = graph.segment(segment);
+                    // Get the vars representing each sement
+                    assert!(
+                        !segment.variables.is_empty(),
+                        "Generate segment variable has not been called on segment {:?}",
+                        segment
+                    );
+                    jiffy_clause.extend(segment.variables.iter().map(|x| x.id() as i64));
+                }
+                let clause = Clause::new(jiffy_clause);
+                clauses.push(clause);
+            }
+        }
+        clauses
+    }
+    pub fn generate_last_project_segment(&self, graph: &SegmentGraph) -> Vec<Clause> {
         // First rules out all projects, second rules out starting project
-        if self.duration > 0 || self.precedence().borrow().len() < 1 {
+        if self.modes.iter().any(|mode| mode.duration > 0) || self.precedence.is_empty() {
             println!("Called Last project seg on wrong project");
         }
         let mut clauses: Vec<Clause> = Vec::new();
-        for segment in self.segments() {
-            for var in segment.borrow().variables.borrow().iter() {
+        for segment in self.segments.iter().map(|id| graph.segment(*id)) {
+            for var in segment.variables.iter() {
                 let clause = Clause::new(vec![var.id() as i64]);
                 // This has to be soft clause, so there needs to be more
                 clauses.push(clause);
@@ -157,114 +206,164 @@ impl<'a> Project<'a> {
         }
         clauses
     }
-    pub fn get_segments_for_jiffy(&self, jiffy: usize) -> Vec<Rc<RefCell<Segment>>> {
-        self.segments
-            .clone()
-            .into_iter()
-            .filter(|s| {
-                s.borrow().start_jiff <= jiffy
-                    && s.borrow().start_jiff + s.borrow().og_duration >= jiffy
+    /// The segments of a mode that start at or before the jiffy and end at or after the jiffy
+    /// before it
+    pub fn get_segments_for_jiffy(
+        &self,
+        graph: &SegmentGraph,
+        mode: usize,
+        jiffy: usize,
+    ) -> Vec<usize> {
+        self.by_start[mode]
+            .iter()
+            .take(jiffy + 1)
+            .flatten()
+            .copied()
+            .filter(|id| {
+                let segment = graph.segment(*id);
+                segment.start_jiff + segment.og_duration >= jiffy
             })
             .collect()
     }
-
-    pub fn duration(&self) -> usize {
-        self.duration
-    }
 }
-impl PartialEq for Project<'_> {
+impl PartialEq for Project {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
     }
 }
-impl PartialOrd for Project<'_> {
+impl PartialOrd for Project {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.id.cmp(&other.id))
+        Some(self.cmp(other))
     }
 }
-impl Ord for Project<'_> {
+impl Ord for Project {
     fn cmp(&self, other: &Self) -> Ordering {
         self.id.cmp(&other.id)
     }
 }
-impl Default for Project<'_> {
-    fn default() -> Self {
-        let mut id_gen = IdGenerator(10000);
-        let resources = vec![1];
-        Project::new(1, 1, resources, RefCell::new(Vec::new()), &mut id_gen)
-    }
-}
-impl Display for Project<'_> {
+impl Display for Project {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let press: Vec<usize> = self
-            .precedence
-            .borrow()
-            .clone()
-            .into_iter()
-            .map(|o| o.id())
-            .collect();
-        let seg: Vec<usize> = self
-            .segments()
-            .iter()
-            .map(|seg| seg.borrow().id())
-            .collect();
         write!(
             f,
-            "Project with ID: {} \nDuration: {}\n Resource: {:?}  \nPrecedents: {:?}\n Segments: {:?}",
-            self.id, self.duration, self.resource, press, seg
+            "Project with ID: {} \nModes: {:?}  \nPrecedents: {:?}\n Segments: {:?}",
+            self.id, self.modes, self.precedence, self.segments
         )
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::cell::RefCell;
-
-    use crate::{id_generator::IdGenerator, project::Project};
+    use crate::{
+        id_generator::IdGenerator,
+        project::{Mode, Project},
+        sat_seg_var::Clause,
+        segment_graph::SegmentGraph,
+    };
 
     #[test]
     fn generte_seg_correct_amount() {
-        let mut id_gen = IdGenerator(0);
-        let projct = Project::new(3, 1, vec![1], RefCell::new(Vec::new()), &mut id_gen);
+        let mut graph = SegmentGraph::new();
+        let projct = Project::new(3, 1, vec![1], &mut graph);
         assert_eq!(projct.segments.len(), (3 * 4) / 2);
+        assert_eq!(graph.len(), (3 * 4) / 2);
     }
     #[test]
     fn generate_first_seg_amount() {
-        let mut id_gen = IdGenerator(0);
-        let projct = Project::new(3, 1, vec![1], RefCell::new(Vec::new()), &mut id_gen);
+        let mut graph = SegmentGraph::new();
+        let projct = Project::new(3, 1, vec![1], &mut graph);
         let first_segements = projct.get_first_segments();
         assert_eq!(first_segements.len(), 3);
     }
     #[test]
     fn generate_last_seg_amount() {
-        let mut id_gen = IdGenerator(0);
-        let projct = Project::new(3, 1, vec![1], RefCell::new(Vec::new()), &mut id_gen);
+        let mut graph = SegmentGraph::new();
+        let projct = Project::new(3, 1, vec![1], &mut graph);
         let last_segments = projct.get_last_segments();
         assert_eq!(last_segments.len(), 3);
     }
     #[test]
     fn link_correctly() {
-        let mut id_gen = IdGenerator::generator_for_segment();
-        let project1 = Project::new(1, 1, Vec::new(), RefCell::new(Vec::new()), &mut id_gen);
-        let project2 = Project::new(1, 2, Vec::new(), RefCell::new(Vec::new()), &mut id_gen);
+        let mut graph = SegmentGraph::new();
+        let project1 = Project::new(1, 1, Vec::new(), &mut graph);
+        let mut project2 = Project::new(1, 2, Vec::new(), &mut graph);
 
-        project2.add_presedence(&project1);
-        project2.link_with_precedents();
+        project2.add_presedence(project1.id());
+        project2.link_with_precedent(&project1, &mut graph);
 
         let project_2_first = project2.get_first_segments();
         let project_1_last = project1.get_last_segments();
 
         for segment_2_first in &project_2_first {
             for segment_1_last in &project_1_last {
-                assert!(segment_2_first
-                    .borrow()
-                    .precedence
-                    .clone()
+                assert!(graph
+                    .predecessors(*segment_2_first)
                     .contains(segment_1_last));
             }
         }
         for segment in project2.segments() {
-            println!("{}", segment.borrow());
+            println!("{}", graph.segment(*segment));
+        }
+    }
+    #[test]
+    fn segments_for_jiffy() {
+        let mut graph = SegmentGraph::new();
+        let projct = Project::new(3, 1, vec![1], &mut graph);
+        for jiffy in 1..4 {
+            let expected: Vec<usize> = graph
+                .segments()
+                .iter()
+                .filter(|s| s.start_jiff <= jiffy && s.start_jiff + s.og_duration >= jiffy)
+                .map(|s| s.id())
+                .collect();
+            let mut found = projct.get_segments_for_jiffy(&graph, 0, jiffy);
+            found.sort();
+            assert_eq!(found, expected);
         }
     }
+    #[test]
+    fn segments_per_mode() {
+        let mut graph = SegmentGraph::new();
+        let modes = vec![Mode::new(2, vec![1]), Mode::new(3, vec![2])];
+        let projct = Project::with_modes(modes, 1, &mut graph);
+        assert_eq!(projct.segments().len(), (2 * 3) / 2 + (3 * 4) / 2);
+        assert_eq!(projct.get_first_segments().len(), 2 + 3);
+        assert_eq!(projct.get_last_segments().len(), 2 + 3);
+        // Segments only follow up on segments of their own mode
+        for segment in projct.segments() {
+            for precedent in graph.predecessors(*segment) {
+                assert_eq!(graph.segment(*precedent).mode, graph.segment(*segment).mode);
+            }
+        }
+    }
+    #[test]
+    fn exactly_one_mode() {
+        let mut graph = SegmentGraph::new();
+        let modes = vec![Mode::new(1, vec![1]), Mode::new(1, vec![2])];
+        let mut projct = Project::with_modes(modes, 1, &mut graph);
+        let mut sat_id_gen = IdGenerator::generator_for_sat();
+        for segment in graph.segments_mut() {
+            segment.generate_SAT_vars(&mut sat_id_gen, 0, 0);
+        }
+        projct.generate_mode_vars(&mut sat_id_gen);
+        let mode_variables = projct.mode_variables();
+        assert_eq!(mode_variables.len(), 2);
+        let (first, second) = (mode_variables[0] as i64, mode_variables[1] as i64);
+
+        let clauses = projct.generate_mode_clauses(&graph);
+        assert!(clauses.contains(&Clause::new(vec![first, second])));
+        assert!(clauses.contains(&Clause::new(vec![-first, -second])));
+        // Every completion clause is conditional on its mode
+        for clause in projct.generate_completion_clauses(&graph) {
+            assert!(clause.arguments().contains(&-first) || clause.arguments().contains(&-second));
+        }
+    }
+    #[test]
+    fn single_mode_has_no_mode_variables() {
+        let mut graph = SegmentGraph::new();
+        let mut id_gen = IdGenerator::generator_for_sat();
+        let mut projct = Project::new(2, 1, vec![1], &mut graph);
+        projct.generate_mode_vars(&mut id_gen);
+        assert!(projct.mode_variables().is_empty());
+        assert!(projct.generate_mode_clauses(&graph).is_empty());
+    }
 }
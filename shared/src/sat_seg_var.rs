@@ -1,12 +1,10 @@
-use std::{iter::zip, rc::Rc};
+use std::iter::zip;
 
 use itertools::Itertools;
 
-use crate::id_generator::IdGenerator;
+use crate::{id_generator::IdGenerator, pb_encoding::PBEncoding};
 
-use pyo3::prelude::*;
-
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub struct SATSVar {
     id: usize,
     segment_id: usize,
@@ -39,17 +37,17 @@ impl SATSVar {
         time: usize,
         id_gen: &mut IdGenerator,
         resource_usage: Vec<usize>,
-    ) -> Vec<Rc<SATUVar>> {
-        let mut u_vars: Vec<Rc<SATUVar>> = Vec::new();
+    ) -> Vec<SATUVar> {
+        let mut u_vars: Vec<SATUVar> = Vec::new();
         if segment_duration == 0 {
             let resource = resource_usage;
             let u_var = SATUVar::new(id_gen.next_id(), segment_id, time as usize, resource);
-            u_vars.push(Rc::new(u_var));
+            u_vars.push(u_var);
         } else {
             for l in (time as usize)..(time as usize) + segment_duration {
                 let resource = resource_usage.clone();
                 let u_var = SATUVar::new(id_gen.next_id(), segment_id, l, resource);
-                u_vars.push(Rc::new(u_var));
+                u_vars.push(u_var);
             }
         }
         u_vars
@@ -63,6 +61,10 @@ impl SATSVar {
         self.time
     }
 
+    pub fn segment_id(&self) -> usize {
+        self.segment_id
+    }
+
     pub fn weight(&self) -> bool {
         self.weight
     }
@@ -74,7 +76,7 @@ impl SATSVar {
     pub fn id_mut(&mut self) -> &mut usize {
         &mut self.id
     }
-    pub fn last_to_clause(vars: &Vec<Rc<SATSVar>>) -> Vec<Clause> {
+    pub fn last_to_clause(vars: &[&SATSVar]) -> Vec<Clause> {
         vars.iter().map(|u| u.to_clause()).collect()
     }
     pub fn to_clause(&self) -> Clause {
@@ -82,7 +84,7 @@ impl SATSVar {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub struct SATUVar {
     id: usize,
     segment_id: usize,
@@ -99,8 +101,9 @@ impl SATUVar {
             resource_usage,
         }
     }
-    pub fn linear_sum_split(vars: &Vec<Rc<SATUVar>>) -> Vec<Vec<&Rc<SATUVar>>> {
+    pub fn linear_sum_split<'v>(vars: &[&'v SATUVar]) -> Vec<Vec<&'v SATUVar>> {
         vars.iter()
+            .copied()
             .into_group_map_by(|a| a.time_at())
             .into_values()
             .collect()
@@ -172,78 +175,93 @@ impl Clause {
             + &top.to_string()
             + "\n"
     }
-    pub fn python_max(
-        lit: Vec<usize>,
-        weight: Vec<usize>,
-        total: usize,
-        highest_id_yet: usize,
-    ) -> PyResult<Vec<Vec<i64>>> {
-        Python::with_gil(|py| {
-            let fun: Py<PyAny> = PyModule::from_code(
-                py,
-                "
-def function(lits, weights, bound, top_id):
-    import pysat.pb
-    cnf = pysat.pb.PBEnc.atmost(lits=lits, weights=weights, bound=bound, top_id=top_id)
-    return cnf.clauses",
-                "",
-                "",
-            )?
-            .getattr("function")?
-            .into();
-            let result: Vec<Vec<i64>> = fun
-                .call1(py, (lit, weight, total, highest_id_yet))?
-                .extract(py)?;
-            Ok(result)
-        })
-    }
-    pub fn integer_vec_to_clause(list: Vec<Vec<i64>>) -> Vec<Clause> {
-        let mut output: Vec<Clause> = Vec::new();
-        for l in list.into_iter() {
-            let clause = Clause::new(l);
-            output.push(clause);
-        }
-        output
-    }
     pub fn u_vec_accum(
-        u_vars: Vec<Rc<SATUVar>>,
-        max_current: &mut IdGenerator,
+        u_vars: Vec<&SATUVar>,
+        id_gen: &mut IdGenerator,
         resources: Vec<usize>,
+        encoding: PBEncoding,
     ) -> Vec<Clause> {
         // split into times for u vars
         let mut output: Vec<Clause> = Vec::new();
         let split_by_time = SATUVar::linear_sum_split(&u_vars);
         for u_time in split_by_time.iter() {
+            // then generate for each resource
             for (index, resource) in resources.iter().enumerate() {
-                let u_ids: Vec<usize> = u_time.iter().map(|c| c.id()).collect();
+                let u_ids: Vec<i64> = u_time.iter().map(|c| c.id() as i64).collect();
                 let u_weights: Vec<usize> =
                     u_time.iter().map(|u| u.resource_usage[index]).collect();
-                let lits = Clause::python_max(
-                    u_ids,
-                    u_weights,
-                    *resource,
-                    max_current.current_asignment(),
-                )
-                .unwrap();
-                if !lits.is_empty() {
-                    let max_next = lits
-                        .iter()
-                        .flatten()
-                        .map(|a| a.unsigned_abs())
-                        .max()
-                        .unwrap();
-                    max_current.new_current(max_next as usize);
-                }
-                let mut gen_clauses = Clause::integer_vec_to_clause(lits);
+                let mut gen_clauses =
+                    encoding.encode_at_most(&u_ids, &u_weights, *resource, id_gen);
                 output.append(&mut gen_clauses);
             }
         }
         output
+    }
 
-        // then generate for each resource
-        // add them to the return
-        //
-        // return it all
+    pub fn u_vec_capacity(
+        u_vars: Vec<&SATUVar>,
+        resources: Vec<usize>,
+    ) -> Vec<CapacityConstraint> {
+        let mut output: Vec<CapacityConstraint> = Vec::new();
+        let split_by_time = SATUVar::linear_sum_split(&u_vars);
+        for u_time in split_by_time.iter() {
+            for (index, resource) in resources.iter().enumerate() {
+                let terms: Vec<(usize, usize)> = u_time
+                    .iter()
+                    .map(|u| (u.resource_usage[index], u.id()))
+                    .filter(|(weight, _)| *weight > 0)
+                    .collect();
+                // no need to constrain a time point that can never be overloaded
+                if terms.iter().map(|(weight, _)| weight).sum::<usize>() <= *resource {
+                    continue;
+                }
+                output.push(CapacityConstraint {
+                    resource: index,
+                    capacity: *resource,
+                    terms,
+                });
+            }
+        }
+        output
+    }
+
+    pub fn arguments(&self) -> &[i64] {
+        self.arguments.as_ref()
+    }
+}
+
+/// The resource usage at a single time point, sum(weight * u) <= capacity, written as a line that
+/// pumpkin turns into a propagator instead of a clausal encoding:
+/// r [resource] [capacity] [weight_1] [u_1] ... [weight_n] [u_n] 0
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CapacityConstraint {
+    resource: usize,
+    capacity: usize,
+    terms: Vec<(usize, usize)>,
+}
+
+impl CapacityConstraint {
+    pub fn new(resource: usize, capacity: usize, terms: Vec<(usize, usize)>) -> Self {
+        Self {
+            resource,
+            capacity,
+            terms,
+        }
+    }
+    pub fn write_to_string(&self) -> String {
+        format!("r {} {} ", self.resource, self.capacity)
+            + &self
+                .terms
+                .iter()
+                .map(|(weight, id)| format!("{} {} ", weight, id))
+                .collect::<String>()
+            + "0"
+    }
+    pub fn write_list_to_string(constraints: &[CapacityConstraint]) -> String {
+        constraints
+            .iter()
+            .map(|x| x.write_to_string() + "\n")
+            .collect::<String>()
     }
 }
 
@@ -269,11 +287,4 @@ mod tests {
         let early_start = 1;
         let latest_start = 3;
     }
-    #[test]
-    fn python_in_rust() {
-        println!(
-            "{:?}",
-            Clause::python_max(vec![1, 2, 3], vec![1, 2, 3], 3, 0)
-        );
-    }
 }
@@ -1,28 +1,26 @@
-use std::{
-    cell::RefCell,
-    fmt::{self, Display},
-    mem,
-    rc::Rc,
-};
+use std::fmt::{self, Display};
 
 use crate::{
     id_generator::IdGenerator,
     sat_seg_var::{Clause, SATSVar, SATUVar},
 };
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// A piece of a project in one of its modes, running from `start_jiff` for `og_duration` jiffies.
+/// Segments only know their own data, how they are connected is kept in the
+/// [`SegmentGraph`](crate::segment_graph::SegmentGraph) that owns them.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub struct Segment {
     pub start_jiff: usize,
     pub duration: usize,
-    //TODO: Replace with HashSet to prevent duplicates
-    pub precedence: Vec<Rc<RefCell<Segment>>>,
     pub id: usize,
     pub parent_project: usize,
+    /// Index of the mode of the parent project this segment belongs to
+    pub mode: usize,
     // TODO: Perhaps there is a better way to deal with this resource array
     // Investigate
     pub resource: Vec<usize>,
-    pub variables: RefCell<Vec<Rc<SATSVar>>>,
-    pub uvariables: RefCell<Vec<Rc<SATUVar>>>,
+    pub variables: Vec<SATSVar>,
+    pub uvariables: Vec<SATUVar>,
     pub early_start: usize,
     pub latest_start: usize,
     pub og_duration: usize,
@@ -32,71 +30,44 @@ impl Segment {
     pub fn new(
         start_jiff: usize,
         duration: usize,
-        precedence: Vec<Rc<RefCell<Segment>>>,
         id: usize,
         parent_project: usize,
+        mode: usize,
         resource: Vec<usize>,
     ) -> Self {
-        let variables: RefCell<Vec<Rc<SATSVar>>> = RefCell::new(Vec::new());
-        let uvariables: RefCell<Vec<Rc<SATUVar>>> = RefCell::new(Vec::new());
-        let early_start = 0;
-        let latest_start = 0;
-        let og_duration = duration;
-        let duration = duration;
-        let mut has_set_up = false;
-        if start_jiff == 1 && duration > 0 {
-            has_set_up = true;
-        }
+        let has_set_up = start_jiff == 1 && duration > 0;
 
         Self {
             start_jiff,
             duration,
-            precedence,
             id,
             parent_project,
+            mode,
             resource,
-            variables,
-            uvariables,
-            early_start,
-            latest_start,
-            og_duration,
+            variables: Vec::new(),
+            uvariables: Vec::new(),
+            early_start: 0,
+            latest_start: 0,
+            og_duration: duration,
             has_set_up,
         }
     }
 
-    /// .
-    pub fn add_precedent(&mut self, precedent: &Rc<RefCell<Segment>>) {
-        assert_ne!(self.parent_project, precedent.borrow().parent_project);
-        self.precedence.push(precedent.clone());
-    }
-    pub fn add_precedents(&mut self, precedents: &Vec<Rc<RefCell<Segment>>>) {
-        for precedent in precedents {
-            self.add_precedent(precedent);
-        }
-    }
-    pub fn precedence_link(last: &Vec<Rc<RefCell<Segment>>>, first: &Vec<Rc<RefCell<Segment>>>) {
-        for f in first {
-            f.borrow_mut().add_precedents(last);
-        }
-    }
-
     pub fn id(&self) -> usize {
         self.id
     }
-    pub fn add_set_up_time(&mut self, set_up_cost: usize) {
-        let press_parents: Vec<usize> = self
-            .precedence
-            .iter()
-            .map(|o| o.borrow().parent_project)
-            .collect();
 
-        if press_parents.contains(&self.parent_project) && self.duration() > 0 {
-            self.duration += set_up_cost;
-        }
+    /// The last jiffy of the project covered by this segment, 0 for a segment of an empty mode
+    pub fn end_jiff(&self) -> usize {
+        (self.start_jiff + self.og_duration).saturating_sub(1)
     }
 
-    pub fn precedence(&self) -> &Vec<Rc<RefCell<Segment>>> {
-        &self.precedence
+    pub fn add_set_up_time(&mut self, set_up_cost: usize) {
+        // Segments that do not start at the first jiffy resume their project after a preemption.
+        // Relative to the original duration, so the same segments can be reused for several costs
+        if self.start_jiff > 1 && self.og_duration > 0 {
+            self.duration = self.og_duration + set_up_cost;
+        }
     }
 
     pub fn duration(&self) -> usize {
@@ -111,59 +82,30 @@ impl Segment {
         early_start: usize,
         latest_start: usize,
     ) {
-        let mut sat_vars: Vec<Rc<SATSVar>> = Vec::new();
-        let mut sat_u_vars: Vec<Rc<SATUVar>> = Vec::new();
-        for t in early_start..latest_start + 1 {
-            let resource = self.resource.clone();
-            let sat_var = SATSVar::new(
-                self.id(),
-                self.duration(),
-                t,
-                id_gen,
-                resource,
-                self.has_set_up,
-            );
-            sat_vars.push(Rc::new(sat_var));
-        }
-        for t in early_start..latest_start + 1 + self.duration() {
-            let resource = self.resource.clone();
-            let u_var = SATUVar::new(id_gen.next_id(), self.id(), t, resource);
-            sat_u_vars.push(Rc::new(u_var));
-        }
-        let mut new_u_var = RefCell::new(sat_u_vars);
-        let mut new_s_var = RefCell::new(sat_vars);
-
-        mem::swap(&mut self.uvariables, &mut new_u_var);
-        mem::swap(&mut self.variables, &mut new_s_var);
+        self.variables = (early_start..latest_start + 1)
+            .map(|t| {
+                SATSVar::new(
+                    self.id(),
+                    self.duration(),
+                    t,
+                    id_gen,
+                    self.resource.clone(),
+                    self.has_set_up,
+                )
+            })
+            .collect();
+        self.uvariables = (early_start..latest_start + 1 + self.duration())
+            .map(|t| SATUVar::new(id_gen.next_id(), self.id(), t, self.resource.clone()))
+            .collect();
 
         self.latest_start = latest_start;
         self.early_start = early_start;
-        drop(new_s_var);
-        drop(new_u_var);
-    }
-    pub fn generate_precedence_clauses(&self) -> Vec<Clause> {
-        let mut clauses: Vec<Clause> = Vec::new();
-        self.variables.borrow().iter().for_each(|sat_var| {
-            if !self.precedence().is_empty() {
-                let mut sat_var_clause = vec![-(sat_var.id() as i64)];
-                for pred in self.precedence.iter() {
-                    for pred_sat in pred.borrow().variables.borrow().iter().filter(|v| {
-                        v.time() <= self.early_start - (pred.borrow().duration() as usize)
-                    }) {
-                        sat_var_clause.push(pred_sat.id() as i64);
-                    }
-                }
-                let clause = Clause::new(sat_var_clause);
-                clauses.push(clause);
-            }
-        });
-        clauses
     }
 
     pub fn generate_consistency_clause(&self) -> Vec<Clause> {
         let mut clauses: Vec<Clause> = Vec::new();
-        for s_var in self.variables.borrow().iter() {
-            for u_var in self.uvariables.borrow().iter() {
+        for s_var in self.variables.iter() {
+            for u_var in self.uvariables.iter() {
                 if u_var.time_at() >= s_var.time()
                     && u_var.time_at() <= s_var.time() + self.duration()
                 {
@@ -179,16 +121,10 @@ impl Segment {
 }
 impl Display for Segment {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let press: Vec<usize> = self
-            .precedence
-            .clone()
-            .into_iter()
-            .map(|o| o.borrow().id())
-            .collect();
         write!(
             f,
-            "Segment with ID: {} \nParent ID: {}\nDuration: {}\n Resource: {:?}  \nPrecedents: {:?}\n",
-            self.id, self.parent_project, self.duration, self.resource, press
+            "Segment with ID: {} \nParent ID: {}\nDuration: {}\n Resource: {:?}\n",
+            self.id, self.parent_project, self.duration, self.resource
         )
     }
 }
@@ -198,4 +134,22 @@ mod tests {
     use crate::segment::Segment;
     #[test]
     fn link_test() {}
+
+    #[test]
+    fn set_up_time_is_not_cumulative() {
+        let mut second = Segment::new(2, 2, 1, 1, 0, vec![1]);
+        second.add_set_up_time(3);
+        assert_eq!(second.duration(), 5);
+        second.add_set_up_time(1);
+        assert_eq!(second.duration(), 3);
+        second.add_set_up_time(0);
+        assert_eq!(second.duration(), 2);
+    }
+
+    #[test]
+    fn first_segment_has_no_set_up_time() {
+        let mut first = Segment::new(1, 2, 0, 1, 0, vec![1]);
+        first.add_set_up_time(3);
+        assert_eq!(first.duration(), 2);
+    }
 }
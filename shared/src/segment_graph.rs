@@ -0,0 +1,203 @@
+use std::collections::VecDeque;
+
+use crate::{sat_seg_var::Clause, segment::Segment};
+
+/// Owns every segment of a schedule, with the id of a segment being its index. Precedence
+/// between segments is kept as adjacency lists, so nothing in the model needs shared ownership
+/// and the whole graph can be cloned or sent to another thread.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SegmentGraph {
+    segments: Vec<Segment>,
+    predecessors: Vec<Vec<usize>>,
+    successors: Vec<Vec<usize>>,
+}
+
+impl SegmentGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a segment without any precedence and returns its id
+    pub fn add_segment(
+        &mut self,
+        start_jiff: usize,
+        duration: usize,
+        parent_project: usize,
+        mode: usize,
+        resource: Vec<usize>,
+    ) -> usize {
+        let id = self.segments.len();
+        self.segments.push(Segment::new(
+            start_jiff,
+            duration,
+            id,
+            parent_project,
+            mode,
+            resource,
+        ));
+        self.predecessors.push(Vec::new());
+        self.successors.push(Vec::new());
+        id
+    }
+
+    /// `from` has to be done before `to` can start
+    pub fn add_precedence(&mut self, from: usize, to: usize) {
+        self.predecessors[to].push(from);
+        self.successors[from].push(to);
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    pub fn segment(&self, id: usize) -> &Segment {
+        &self.segments[id]
+    }
+
+    pub fn segment_mut(&mut self, id: usize) -> &mut Segment {
+        &mut self.segments[id]
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        self.segments.as_ref()
+    }
+
+    pub fn segments_mut(&mut self) -> &mut [Segment] {
+        self.segments.as_mut()
+    }
+
+    pub fn predecessors(&self, id: usize) -> &[usize] {
+        self.predecessors[id].as_ref()
+    }
+
+    pub fn successors(&self, id: usize) -> &[usize] {
+        self.successors[id].as_ref()
+    }
+
+    /// The segments ordered such that every segment comes after its predecessors
+    pub fn topological_order(&self) -> Vec<usize> {
+        let mut in_degree: Vec<usize> = self.predecessors.iter().map(|p| p.len()).collect();
+        let mut queue: VecDeque<usize> = (0..self.len()).filter(|id| in_degree[*id] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+            for successor in self.successors[id].iter() {
+                in_degree[*successor] -= 1;
+                if in_degree[*successor] == 0 {
+                    queue.push_back(*successor);
+                }
+            }
+        }
+        assert_eq!(
+            order.len(),
+            self.len(),
+            "The segment graph contains a cycle"
+        );
+        order
+    }
+
+    /// The earliest start of every segment, as the shortest path from a segment without
+    /// predecessors. A segment only needs one of its predecessors to be done.
+    pub fn earliest_starts(&self) -> Vec<usize> {
+        let mut earliest = vec![0; self.len()];
+        for id in self.topological_order() {
+            earliest[id] = self.predecessors[id]
+                .iter()
+                .map(|pred| earliest[*pred] + self.segments[*pred].duration())
+                .min()
+                .unwrap_or(0);
+        }
+        earliest
+    }
+
+    /// The length of the longest path through the graph, which is used as the horizon
+    pub fn critical_path(&self) -> usize {
+        let mut longest = vec![0; self.len()];
+        for id in self.topological_order() {
+            longest[id] = self.predecessors[id]
+                .iter()
+                .map(|pred| longest[*pred] + self.segments[*pred].duration())
+                .max()
+                .unwrap_or(0);
+        }
+        longest.into_iter().max().unwrap_or(0)
+    }
+
+    /// A segment can only start once one of its predecessors is done
+    pub fn generate_precedence_clauses(&self, id: usize) -> Vec<Clause> {
+        let predecessors = &self.predecessors[id];
+        if predecessors.is_empty() {
+            return Vec::new();
+        }
+        self.segments[id]
+            .variables
+            .iter()
+            .map(|sat_var| {
+                let mut sat_var_clause = vec![-(sat_var.id() as i64)];
+                for pred in predecessors.iter().map(|pred| &self.segments[*pred]) {
+                    // The predecessor has to be done by the time this segment starts. In a
+                    // multi-mode project its longer modes may not fit at all
+                    for pred_sat in pred
+                        .variables
+                        .iter()
+                        .filter(|v| v.time() + pred.duration() <= sat_var.time())
+                    {
+                        sat_var_clause.push(pred_sat.id() as i64);
+                    }
+                }
+                Clause::new(sat_var_clause)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SegmentGraph;
+
+    // 0 -> 1 -> 3 and 0 -> 2 -> 3, where 2 takes longer than 1
+    fn diamond() -> SegmentGraph {
+        let mut graph = SegmentGraph::new();
+        for duration in [1, 2, 5, 0] {
+            graph.add_segment(1, duration, 1, 0, vec![1]);
+        }
+        graph.add_precedence(0, 1);
+        graph.add_precedence(0, 2);
+        graph.add_precedence(1, 3);
+        graph.add_precedence(2, 3);
+        graph
+    }
+
+    #[test]
+    fn adjacency_lists() {
+        let graph = diamond();
+        assert_eq!(graph.predecessors(3), &[1, 2]);
+        assert_eq!(graph.successors(0), &[1, 2]);
+        assert!(graph.predecessors(0).is_empty());
+    }
+
+    #[test]
+    fn earliest_starts_follow_shortest_path() {
+        assert_eq!(diamond().earliest_starts(), vec![0, 1, 1, 3]);
+    }
+
+    #[test]
+    fn critical_path_is_longest_path() {
+        assert_eq!(diamond().critical_path(), 6);
+    }
+
+    #[test]
+    fn topological_order_ignores_ids() {
+        let mut graph = SegmentGraph::new();
+        for _ in 0..3 {
+            graph.add_segment(1, 1, 1, 0, vec![1]);
+        }
+        graph.add_precedence(2, 0);
+        graph.add_precedence(0, 1);
+        assert_eq!(graph.topological_order(), vec![2, 0, 1]);
+    }
+}